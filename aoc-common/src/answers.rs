@@ -0,0 +1,223 @@
+//! The registry of known answers of every day, kept next to the inputs in
+//! `data/answers.txt`, and the checks that solve the inputs against it.
+//!
+//! Every non-empty line of the registry is `<input file> <part> <answer>`.
+//! Lines starting with `#` are comments.
+
+use crate::{
+    error::ParseError,
+    input::{lines, load_input},
+    puzzle::{parse_solution, parse_solution_file, Parser, Part, Puzzle},
+    table::format_table,
+};
+use std::path::Path;
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    /// The input file, relative to the day's data directory.
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+pub fn parse_answers(text: &str) -> Result<Vec<Answer>, ParseError> {
+    let mut answers = vec![];
+    for line in lines(text) {
+        let line = line.trim();
+        if line.text.is_empty() || line.text.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<_> = line.split_whitespace().collect();
+        if fields.len() != 3 {
+            return Err(line.error("an input file, a part and an answer"));
+        }
+        let part = match fields[1].text {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => return Err(fields[1].error("`1` or `2`")),
+        };
+        answers.push(Answer {
+            input: fields[0].text.to_string(),
+            part,
+            answer: fields[2].text.to_string(),
+        });
+    }
+    Ok(answers)
+}
+
+/// Read the answers registered in `data_dir`.
+pub fn load_answers(data_dir: &Path) -> Result<Vec<Answer>, ParseError> {
+    let filename = data_dir.join(ANSWERS_FILE);
+    let text = load_input(&filename)?;
+    parse_answers(&text).map_err(|error| error.with_file(&filename))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    /// The day's answer, which isn't the registered one.
+    Wrong(String),
+    /// The input couldn't be read or parsed, or the part has no answer.
+    Error(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub input: String,
+    pub part: Part,
+    pub expected: String,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Pass
+    }
+}
+
+/// Solve `answers` with `parser`, reading their inputs from `data_dir`. Every
+/// input is only parsed once, no matter how many parts it has answers for.
+pub fn check_answers(data_dir: &Path, answers: &[Answer], parser: Parser) -> Vec<Check> {
+    let mut checks = vec![];
+    let mut inputs: Vec<&str> = vec![];
+    for answer in answers {
+        if !inputs.contains(&answer.input.as_str()) {
+            inputs.push(&answer.input);
+        }
+    }
+    for input in inputs {
+        let solution = parse_solution_file(parser, &data_dir.join(input));
+        for answer in answers.iter().filter(|a| a.input == input) {
+            let outcome = match &solution {
+                Ok(solution) => match solution.solve(answer.part) {
+                    Ok(actual) if actual == answer.answer => Outcome::Pass,
+                    Ok(actual) => Outcome::Wrong(actual),
+                    Err(error) => Outcome::Error(error.to_string()),
+                },
+                Err(error) => Outcome::Error(error.to_string()),
+            };
+            checks.push(Check {
+                input: answer.input.clone(),
+                part: answer.part,
+                expected: answer.answer.clone(),
+                outcome,
+            });
+        }
+    }
+    checks
+}
+
+/// Solve every answer registered in `data_dir` with `parser`.
+pub fn verify(data_dir: &Path, parser: Parser) -> Result<Vec<Check>, ParseError> {
+    let answers = load_answers(data_dir)?;
+    Ok(check_answers(data_dir, &answers, parser))
+}
+
+/// Lay out `checks` as a table, one row per check.
+pub fn format_checks(checks: &[Check]) -> String {
+    let mut rows = vec![vec![
+        "Input".to_string(),
+        "Part".to_string(),
+        "Expected".to_string(),
+        "Actual".to_string(),
+        "Result".to_string(),
+    ]];
+    for check in checks {
+        let (actual, result) = match &check.outcome {
+            Outcome::Pass => (check.expected.clone(), "pass"),
+            Outcome::Wrong(actual) => (actual.clone(), "FAIL"),
+            Outcome::Error(error) => (error.clone(), "ERROR"),
+        };
+        rows.push(vec![
+            check.input.clone(),
+            check.part.to_string(),
+            check.expected.clone(),
+            actual,
+            result.to_string(),
+        ]);
+    }
+    format_table(&rows)
+}
+
+/// Test helper that panics, showing what went wrong, unless `P` finds every
+/// answer registered for `input` in `data_dir`. Registering no answers for
+/// `input` is a failure too.
+pub fn assert_answers<P>(data_dir: &str, input: &str)
+where
+    P: Puzzle + 'static,
+{
+    let data_dir = Path::new(data_dir);
+    let answers: Vec<Answer> = load_answers(data_dir)
+        .unwrap_or_else(|error| panic!("{error}"))
+        .into_iter()
+        .filter(|answer| answer.input == input)
+        .collect();
+    assert!(
+        !answers.is_empty(),
+        "no answers registered for {input} in {}",
+        data_dir.join(ANSWERS_FILE).display()
+    );
+    let checks = check_answers(data_dir, &answers, parse_solution::<P>);
+    assert!(
+        checks.iter().all(Check::passed),
+        "wrong answers for {input}:\n{}",
+        format_checks(&checks)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("# input part answer\nexample.txt 1 35\n\ninput.txt 2 -7\n");
+        assert_eq!(
+            answers.unwrap(),
+            vec![
+                Answer {
+                    input: "example.txt".to_string(),
+                    part: Part::One,
+                    answer: "35".to_string(),
+                },
+                Answer {
+                    input: "input.txt".to_string(),
+                    part: Part::Two,
+                    answer: "-7".to_string(),
+                }
+            ]
+        );
+        assert_eq!(
+            parse_answers("example.txt 3 35").unwrap_err().to_string(),
+            "line 1, column 13: expected `1` or `2`, found `3`"
+        );
+    }
+
+    #[test]
+    fn test_format_checks() {
+        let checks = vec![
+            Check {
+                input: "example.txt".to_string(),
+                part: Part::One,
+                expected: "35".to_string(),
+                outcome: Outcome::Pass,
+            },
+            Check {
+                input: "input.txt".to_string(),
+                part: Part::Two,
+                expected: "46".to_string(),
+                outcome: Outcome::Wrong("4".to_string()),
+            },
+        ];
+        assert_eq!(
+            format_checks(&checks),
+            "\
+Input        Part  Expected  Actual  Result
+example.txt  1     35        35      pass
+input.txt    2     46        4       FAIL
+"
+        );
+    }
+}
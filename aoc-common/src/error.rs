@@ -0,0 +1,183 @@
+//! The errors every day reports when its puzzle input can't be parsed, or
+//! parses but has no answer.
+
+use crate::numeric::Overflow;
+use std::{
+    error, fmt, io,
+    num::ParseIntError,
+    path::{Path, PathBuf},
+};
+
+/// Where something was found in the puzzle input. Lines and columns start at
+/// 1, like in any editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize) -> Self {
+        Location {
+            file: None,
+            line,
+            column,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file.display(), self.line, self.column),
+            None => write!(f, "line {}, column {}", self.line, self.column),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseError {
+    /// The puzzle input couldn't be read. `file` is `None` when it came from
+    /// a reader rather than a file.
+    Io {
+        file: Option<PathBuf>,
+        source: io::Error,
+    },
+    /// The puzzle input ended while `expected` was still missing.
+    UnexpectedEnd {
+        location: Location,
+        expected: String,
+    },
+    /// `text` was found where `expected` should have been.
+    BadToken {
+        location: Location,
+        text: String,
+        expected: String,
+    },
+    /// `text` should have been a number, but isn't one.
+    BadNumber {
+        location: Location,
+        text: String,
+        source: ParseIntError,
+    },
+}
+
+impl ParseError {
+    /// The location of the error, unless the input couldn't even be read.
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            ParseError::Io { .. } => None,
+            ParseError::UnexpectedEnd { location, .. }
+            | ParseError::BadToken { location, .. }
+            | ParseError::BadNumber { location, .. } => Some(location),
+        }
+    }
+
+    /// Record that the error was found in the puzzle input at `file`.
+    pub fn with_file<P>(mut self, file: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        match &mut self {
+            ParseError::Io { file: name, .. } => {
+                *name = Some(file.as_ref().to_path_buf());
+            }
+            ParseError::UnexpectedEnd { location, .. }
+            | ParseError::BadToken { location, .. }
+            | ParseError::BadNumber { location, .. } => {
+                location.file = Some(file.as_ref().to_path_buf());
+            }
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Io {
+                file: Some(file),
+                source,
+            } => write!(f, "{}: unable to read input: {}", file.display(), source),
+            ParseError::Io { file: None, source } => {
+                write!(f, "unable to read input: {source}")
+            }
+            ParseError::UnexpectedEnd { location, expected } => {
+                write!(
+                    f,
+                    "{location}: expected {expected}, found the end of the input"
+                )
+            }
+            ParseError::BadToken {
+                location,
+                text,
+                expected,
+            } => write!(f, "{location}: expected {expected}, found `{text}`"),
+            ParseError::BadNumber {
+                location,
+                text,
+                source,
+            } => write!(f, "{location}: invalid number `{text}`: {source}"),
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ParseError::Io { source, .. } => Some(source),
+            ParseError::BadNumber { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Why a part has no answer, although its puzzle input was parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The puzzle input describes something the part has no answer for, such
+    /// as a path that never reaches its end.
+    Unsolvable(String),
+    /// The answer, or a step towards it, outgrew its type.
+    Overflow(Overflow),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Unsolvable(reason) => write!(f, "{reason}"),
+            SolveError::Overflow(overflow) => write!(f, "overflow at {overflow}"),
+        }
+    }
+}
+
+impl error::Error for SolveError {}
+
+impl From<Overflow> for SolveError {
+    fn from(overflow: Overflow) -> Self {
+        SolveError::Overflow(overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::BadToken {
+            location: Location::new(3, 7),
+            text: "X".to_string(),
+            expected: "`L` or `R`".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 7: expected `L` or `R`, found `X`"
+        );
+        assert_eq!(
+            error.with_file("data/input.txt").to_string(),
+            "data/input.txt:3:7: expected `L` or `R`, found `X`"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Breaks an answer down into what every item of the input contributed to it,
+//! to justify the answer line by line.
+
+use crate::{
+    math::lcm_of,
+    numeric::{self, Overflow},
+    puzzle::{Part, Puzzle},
+    table::format_table,
+};
+use std::{fmt, path::Path};
+
+/// How the contributions of the items make up the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Sum,
+    Product,
+    Minimum,
+    LeastCommonMultiple,
+}
+
+impl fmt::Display for Combine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Combine::Sum => write!(f, "sum"),
+            Combine::Product => write!(f, "product"),
+            Combine::Minimum => write!(f, "minimum"),
+            Combine::LeastCommonMultiple => write!(f, "least common multiple"),
+        }
+    }
+}
+
+/// What one item of the input, such as a line or a game, contributed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contribution {
+    /// The item, such as `Game 3`.
+    pub item: String,
+    /// Why the item contributed its value.
+    pub detail: String,
+    pub value: i128,
+}
+
+impl Contribution {
+    /// The contribution of `item`. Only the 128-bit unsigned answers of the
+    /// `wide` feature could make a `value` overflow an `i128`.
+    pub fn new<V>(
+        item: impl Into<String>,
+        detail: impl Into<String>,
+        value: V,
+    ) -> Result<Self, Overflow>
+    where
+        V: TryInto<i128> + Copy + fmt::Display,
+    {
+        let item = item.into();
+        Ok(Contribution {
+            value: numeric::convert(value, || item.clone())?,
+            item,
+            detail: detail.into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub combine: Combine,
+    pub contributions: Vec<Contribution>,
+}
+
+impl Explanation {
+    pub fn new(combine: Combine, contributions: Vec<Contribution>) -> Self {
+        Explanation {
+            combine,
+            contributions,
+        }
+    }
+
+    /// The answer the contributions make up, which is `None` if there is no
+    /// minimum because there are no contributions, or the item where it
+    /// overflows.
+    pub fn total(&self) -> Result<Option<i128>, Overflow> {
+        let values = self.contributions.iter().map(|c| c.value);
+        let at = |i: usize| self.contributions[i].item.clone();
+        Ok(match self.combine {
+            Combine::Sum => Some(numeric::sum(values, at)?),
+            Combine::Product => Some(numeric::product(values, at)?),
+            Combine::Minimum => values.min(),
+            Combine::LeastCommonMultiple => Some(lcm_of(values, at)?),
+        })
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut rows = vec![vec![
+            "Item".to_string(),
+            "Detail".to_string(),
+            "Value".to_string(),
+        ]];
+        for contribution in &self.contributions {
+            rows.push(vec![
+                contribution.item.clone(),
+                contribution.detail.clone(),
+                contribution.value.to_string(),
+            ]);
+        }
+        write!(f, "{}", format_table(&rows))?;
+        match self.total() {
+            Ok(Some(total)) => writeln!(f, "Total ({}): {}", self.combine, total),
+            Ok(None) => writeln!(f, "Total ({}): none", self.combine),
+            Err(overflow) => writeln!(f, "Total ({}): overflow at {}", self.combine, overflow),
+        }
+    }
+}
+
+/// Test helper that panics unless `P` explains both answers of `input` in
+/// `data_dir` with contributions that make up the answers.
+pub fn assert_explained<P>(data_dir: &str, input: &str)
+where
+    P: Puzzle,
+{
+    let parsed = P::parse_file(Path::new(data_dir).join(input)).unwrap_or_else(|e| panic!("{e}"));
+    for part in Part::ALL {
+        let explanation = P::explain(&parsed, part)
+            .unwrap_or_else(|e| panic!("part {part} of {input}: {e}"))
+            .unwrap_or_else(|| panic!("part {part} of {input} isn't explained"));
+        assert_eq!(
+            explanation
+                .total()
+                .unwrap_or_else(|e| panic!("part {part} of {input}: {e}"))
+                .map(|total| total.to_string()),
+            Some(
+                P::solve(&parsed, part)
+                    .unwrap_or_else(|e| panic!("part {part} of {input}: {e}"))
+                    .to_string()
+            ),
+            "part {part} of {input} is explained as:\n{explanation}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explanation() {
+        let contributions = vec![
+            Contribution::new("Race 1", "hold 2 to 5", 4).unwrap(),
+            Contribution::new("Race 2", "hold 4 to 11", 8).unwrap(),
+            Contribution::new("Race 3", "hold 11 to 19", 9).unwrap(),
+        ];
+        let explanation = Explanation::new(Combine::Product, contributions.clone());
+        assert_eq!(explanation.total(), Ok(Some(288)));
+        assert_eq!(
+            explanation.to_string(),
+            "Item    Detail         Value\n\
+             Race 1  hold 2 to 5    4\n\
+             Race 2  hold 4 to 11   8\n\
+             Race 3  hold 11 to 19  9\n\
+             Total (product): 288\n"
+        );
+        assert_eq!(
+            Explanation::new(Combine::Sum, contributions.clone()).total(),
+            Ok(Some(21))
+        );
+        assert_eq!(
+            Explanation::new(Combine::Minimum, contributions.clone()).total(),
+            Ok(Some(4))
+        );
+        assert_eq!(
+            Explanation::new(Combine::LeastCommonMultiple, contributions).total(),
+            Ok(Some(72))
+        );
+        assert_eq!(Explanation::new(Combine::Minimum, vec![]).total(), Ok(None));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(
+            Contribution::new("Seed 1", "location", u128::MAX)
+                .unwrap_err()
+                .to_string(),
+            format!("Seed 1: {} overflows i128", u128::MAX)
+        );
+        let contributions = vec![
+            Contribution::new("Seed 2", "location", i128::MAX).unwrap(),
+            Contribution::new("Seed 3", "location", 1).unwrap(),
+        ];
+        let explanation = Explanation::new(Combine::Sum, contributions);
+        assert_eq!(
+            explanation.total().unwrap_err().to_string(),
+            format!("Seed 3: {} + 1 overflows i128", i128::MAX)
+        );
+        assert!(explanation
+            .to_string()
+            .ends_with("Total (sum): overflow at Seed 3: 170141183460469231731687303715884105727 + 1 overflows i128\n"));
+    }
+}
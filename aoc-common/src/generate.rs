@@ -0,0 +1,89 @@
+//! Synthetic puzzle inputs of any size, to stress-test and benchmark the days
+//! beyond the real inputs.
+
+use std::ops::Range;
+
+/// Writes valid puzzle input for a day.
+pub trait Generate {
+    /// Puzzle input whose size grows with `size`, where what `size` counts
+    /// depends on the day. The same `size` and `seed` always give the same
+    /// input.
+    fn generate(size: usize, seed: u64) -> String;
+}
+
+/// A small, seedable random number generator (SplitMix64). It's fast and
+/// reproducible, but not fit for anything beyond generating inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which can't be empty.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "can't pick a number below 0");
+        // Multiplying keeps the bias negligible for the ranges inputs use
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A number in `range`, which can't be empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        range.start + self.below(range.end - range.start)
+    }
+
+    /// A number in `range`, which can't be empty.
+    pub fn range_i64(&mut self, range: Range<i64>) -> i64 {
+        range.start + self.below(range.end.abs_diff(range.start)) as i64
+    }
+
+    /// `true` with the given `probability`.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// One of `items`, which can't be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let numbers: Vec<u64> = (0..5).map(|_| Rng::new(7).below(100)).collect();
+        assert!(numbers.windows(2).all(|pair| pair[0] == pair[1]));
+
+        let mut rng = Rng::new(2023);
+        assert_ne!(rng.next_u64(), rng.next_u64());
+        for _ in 0..1000 {
+            assert!((10..20).contains(&rng.range(10..20)));
+            assert!((-3..4).contains(&rng.range_i64(-3..4)));
+        }
+        let mut items: Vec<u32> = (0..50).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
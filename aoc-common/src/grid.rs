@@ -0,0 +1,319 @@
+//! A rectangular map of cells, for the puzzles whose input is drawn with
+//! characters.
+
+use crate::{error::ParseError, input::lines};
+use std::{
+    fmt,
+    ops::{Index, IndexMut, Range},
+};
+
+/// The position of a cell, counting rows and columns from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, col: usize) -> Self {
+        Position { row, col }
+    }
+
+    /// The position `rows` down and `cols` right of this one, if neither goes
+    /// below 0.
+    pub fn offset(self, rows: isize, cols: isize) -> Option<Position> {
+        Some(Position {
+            row: self.row.checked_add_signed(rows)?,
+            col: self.col.checked_add_signed(cols)?,
+        })
+    }
+}
+
+/// Counting from 1, the way the puzzles do.
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "row {}, column {}", self.row + 1, self.col + 1)
+    }
+}
+
+/// Up, left, right and down.
+const STRAIGHT: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Every direction, row by row.
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// The cells, row by row.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `cell`.
+    pub fn new(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    /// Read a grid drawn with one character per cell, which `cell` turns into
+    /// a cell, or `None` if the character isn't `expected`. Every line is a
+    /// row, and they must all be as long.
+    pub fn parse<F>(input: &str, expected: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in lines(input) {
+            let row_width = line.text.chars().count();
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(line.error(format!("a row of {width} cells")));
+            }
+            for (i, c) in line.text.char_indices() {
+                match cell(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(line.slice(i..(i + c.len_utf8())).error(expected)),
+                }
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.height && position.col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.row * self.width + position.col])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.row * self.width + position.col])
+        } else {
+            None
+        }
+    }
+
+    /// Every position of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Position::new(row, col)))
+    }
+
+    /// Every cell and its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The positions of the cells that match `predicate`, row by row.
+    pub fn find_all<'a, P>(&'a self, predicate: P) -> impl Iterator<Item = Position> + 'a
+    where
+        P: Fn(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    fn around(
+        &self,
+        position: Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |&(rows, cols)| position.offset(rows, cols))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The positions up, left, right and down of `position` that are in the
+    /// grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.around(position, &STRAIGHT)
+    }
+
+    /// The positions all around `position`, diagonals included, that are in
+    /// the grid, row by row.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.around(position, &ALL_AROUND)
+    }
+
+    /// The cells of `row`, which must be in the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {row} is outside of the grid");
+        &self.cells[(row * self.width)..((row + 1) * self.width)]
+    }
+
+    /// The cells of `col` from top to bottom, which must be in the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} is outside of the grid");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// The cells of the `rows` and `cols` that are in the grid, and their
+    /// positions, row by row.
+    pub fn region(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> impl Iterator<Item = (Position, &T)> {
+        let rows = rows.start.min(self.height)..rows.end.min(self.height);
+        let cols = cols.start.min(self.width)..cols.end.min(self.width);
+        rows.flat_map(move |row| {
+            cols.clone().map(move |col| {
+                let position = Position::new(row, col);
+                (position, &self[position])
+            })
+        })
+    }
+
+    /// A grid of the same size with every cell turned into another by `f`.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position} is outside of the grid"))
+    }
+}
+
+/// The grid drawn the way the puzzles do, one line per row.
+impl<T> fmt::Display for Grid<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.height {
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "467..\n...*.\n..35.\n";
+
+    fn map() -> Grid<char> {
+        Grid::parse(MAP, "a character", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = map();
+        assert_eq!((grid.width(), grid.height()), (5, 3));
+        assert_eq!(grid[Position::new(1, 3)], '*');
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.to_string(), MAP);
+        assert_eq!(
+            Grid::parse("12\n345", "a digit", |c| c.to_digit(10))
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected a row of 2 cells, found `345`"
+        );
+        assert_eq!(
+            Grid::parse("12\n3x", "a digit", |c| c.to_digit(10))
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 2: expected a digit, found `x`"
+        );
+        assert_eq!(
+            Grid::parse("", "a digit", |c| c.to_digit(10))
+                .unwrap()
+                .width(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = map();
+        let corner: Vec<Position> = grid.neighbours8(Position::new(0, 0)).collect();
+        assert_eq!(
+            corner,
+            [
+                Position::new(0, 1),
+                Position::new(1, 0),
+                Position::new(1, 1)
+            ]
+        );
+        assert_eq!(grid.neighbours8(Position::new(1, 2)).count(), 8);
+        let edge: Vec<Position> = grid.neighbours4(Position::new(2, 4)).collect();
+        assert_eq!(edge, [Position::new(1, 4), Position::new(2, 3)]);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = map();
+        assert_eq!(grid.row(1), ['.', '.', '.', '*', '.']);
+        assert_eq!(grid.column(2).collect::<String>(), "7.3");
+        let region: String = grid.region(1..5, 2..4).map(|(_, c)| *c).collect();
+        assert_eq!(region, ".*35");
+        let digits: Vec<Position> = grid.find_all(|c| c.is_ascii_digit()).collect();
+        assert_eq!(digits.len(), 5);
+        assert_eq!(digits[3], Position::new(2, 2));
+        assert_eq!(
+            grid.map(|c| *c == '.').to_string().lines().next(),
+            Some("falsefalsefalsetruetrue")
+        );
+        assert_eq!(Position::new(2, 3).to_string(), "row 3, column 4");
+    }
+}
@@ -0,0 +1,308 @@
+//! Loading puzzle inputs and splitting them into the pieces the days work on.
+
+use crate::error::{Location, ParseError};
+use std::{
+    fs,
+    io::{self, BufRead},
+    num::ParseIntError,
+    ops::Range,
+    path::Path,
+    str::FromStr,
+};
+
+/// The filename that stands for the standard input.
+pub const STDIN: &str = "-";
+
+/// Read the whole puzzle input found at `filename`, or the standard input if
+/// `filename` is [`STDIN`].
+pub fn read_input<P>(filename: P) -> io::Result<String>
+where
+    P: AsRef<Path>,
+{
+    if filename.as_ref() == Path::new(STDIN) {
+        read_reader(io::stdin().lock())
+    } else {
+        fs::read_to_string(filename)
+    }
+}
+
+/// Read the whole puzzle input from `reader`.
+pub fn read_reader<R>(mut reader: R) -> io::Result<String>
+where
+    R: BufRead,
+{
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// Like [`read_input`], but reports errors as the [`ParseError`] of `filename`.
+pub fn load_input<P>(filename: P) -> Result<String, ParseError>
+where
+    P: AsRef<Path>,
+{
+    read_input(&filename).map_err(|source| ParseError::Io {
+        file: Some(filename.as_ref().to_path_buf()),
+        source,
+    })
+}
+
+/// Read the puzzle input found at `filename`, one `String` per line.
+pub fn read_lines<P>(filename: P) -> io::Result<Vec<String>>
+where
+    P: AsRef<Path>,
+{
+    Ok(read_input(filename)?.lines().map(String::from).collect())
+}
+
+/// Split `input` into sections separated by blank lines. Each section is the
+/// list of its lines.
+pub fn sections(input: &str) -> Vec<Vec<Span<'_>>> {
+    let mut sections = vec![];
+    let mut current = vec![];
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !current.is_empty() {
+                sections.push(current);
+                current = vec![];
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        sections.push(current);
+    }
+    sections
+}
+
+/// Iterate over the lines of `input`, remembering their line numbers.
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines {
+        lines: input.lines(),
+        line: 0,
+    }
+}
+
+pub struct Lines<'a> {
+    lines: std::str::Lines<'a>,
+    line: usize,
+}
+
+impl<'a> Lines<'a> {
+    /// The next line, which must be there because it holds `expected`.
+    pub fn expect(&mut self, expected: &str) -> Result<Span<'a>, ParseError> {
+        self.next().ok_or_else(|| ParseError::UnexpectedEnd {
+            location: Location::new(self.line + 1, 1),
+            expected: expected.to_string(),
+        })
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Span<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.lines.next()?;
+        self.line += 1;
+        Some(Span::new(text, self.line, 1))
+    }
+}
+
+/// A piece of the puzzle input that remembers where it was found, so that
+/// parse errors can point at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub text: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(text: &'a str, line: usize, column: usize) -> Self {
+        Span { text, line, column }
+    }
+
+    pub fn location(&self) -> Location {
+        Location::new(self.line, self.column)
+    }
+
+    /// The part of the span found at the byte `range` of its text.
+    pub fn slice(&self, range: Range<usize>) -> Span<'a> {
+        Span {
+            text: &self.text[range.start..range.end],
+            line: self.line,
+            column: self.column + self.text[..range.start].chars().count(),
+        }
+    }
+
+    /// The span of `piece`, which must be borrowed from this span's text.
+    fn piece(&self, piece: &'a str) -> Span<'a> {
+        let start = piece.as_ptr() as usize - self.text.as_ptr() as usize;
+        self.slice(start..(start + piece.len()))
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.piece(self.text.trim())
+    }
+
+    pub fn split(&self, delimiter: char) -> impl Iterator<Item = Span<'a>> + '_ {
+        self.text
+            .split(delimiter)
+            .map(move |piece| self.piece(piece))
+    }
+
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Span<'a>> + '_ {
+        self.text
+            .split_whitespace()
+            .map(move |piece| self.piece(piece))
+    }
+
+    /// Split the span around the first `delimiter`, which must be there.
+    pub fn split_once(&self, delimiter: char) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        let (left, right) = self
+            .text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(format!("`{delimiter}`")))?;
+        Ok((self.piece(left), self.piece(right)))
+    }
+
+    /// The rest of the span after `prefix`, which must be there.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.piece(rest)),
+            None => Err(self.error(format!("`{prefix}`"))),
+        }
+    }
+
+    /// The rest of the span before `suffix`, which must be there.
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_suffix(suffix) {
+            Some(rest) => Ok(self.piece(rest)),
+            None => Err(self.error(format!("`{suffix}`"))),
+        }
+    }
+
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr<Err = ParseIntError>,
+    {
+        self.text
+            .parse::<T>()
+            .map_err(|source| ParseError::BadNumber {
+                location: self.location(),
+                text: self.text.to_string(),
+                source,
+            })
+    }
+
+    /// Parse every whitespace-separated number in the span.
+    pub fn numbers<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr<Err = ParseIntError>,
+    {
+        self.split_whitespace().map(|n| n.parse::<T>()).collect()
+    }
+
+    /// The error for the number of this span, which parses, being too large
+    /// for what the puzzle does with it, such as adding it to another.
+    pub fn too_large_error(&self) -> ParseError {
+        // `ParseIntError` can't be built, but any overflow makes the right one
+        let source = "256".parse::<u8>().unwrap_err();
+        ParseError::BadNumber {
+            location: self.location(),
+            text: self.text.to_string(),
+            source,
+        }
+    }
+
+    /// The error for finding this span instead of `expected`.
+    pub fn error<S>(&self, expected: S) -> ParseError
+    where
+        S: Into<String>,
+    {
+        ParseError::BadToken {
+            location: self.location(),
+            text: self.text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// The error for the span ending while `expected` was still missing.
+    pub fn end_error<S>(&self, expected: S) -> ParseError
+    where
+        S: Into<String>,
+    {
+        ParseError::UnexpectedEnd {
+            location: Location::new(self.line, self.column + self.text.chars().count()),
+            expected: expected.into(),
+        }
+    }
+}
+
+/// Parse every whitespace-separated number in `nums`.
+pub fn parse_numbers<T>(nums: &str) -> Result<Vec<T>, T::Err>
+where
+    T: FromStr,
+{
+    nums.split_whitespace().map(|n| n.parse::<T>()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let input = "seeds: 1 2\n\nmap:\n1 2 3\n4 5 6\n\n\nlast:\n7 8 9";
+        let sections = sections(input);
+        let texts: Vec<Vec<&str>> = sections
+            .iter()
+            .map(|section| section.iter().map(|line| line.text).collect())
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                vec!["seeds: 1 2"],
+                vec!["map:", "1 2 3", "4 5 6"],
+                vec!["last:", "7 8 9"]
+            ]
+        );
+        assert_eq!(sections[2][1].location(), Location::new(9, 1));
+    }
+
+    #[test]
+    fn test_span_locations() {
+        let mut lines = lines("Time:      7  15\nDistance:  9  x0");
+        lines.next();
+        let line = lines.expect("distances").unwrap();
+        let (_, distances) = line.split_once(':').unwrap();
+        let numbers: Vec<Span> = distances.split_whitespace().collect();
+        assert_eq!(numbers[1], Span::new("x0", 2, 15));
+        assert!(matches!(
+            distances.numbers::<u32>(),
+            Err(ParseError::BadNumber { location, .. }) if location == Location::new(2, 15)
+        ));
+        assert!(matches!(
+            lines.expect("more races"),
+            Err(ParseError::UnexpectedEnd { location, .. }) if location == Location::new(3, 1)
+        ));
+        assert!(matches!(
+            line.trim().split_once('|'),
+            Err(ParseError::BadToken { location, .. }) if location == Location::new(2, 1)
+        ));
+    }
+
+    #[test]
+    fn test_read_reader() {
+        let input = "0 3 6 9\n1 3 6 10\n";
+        assert_eq!(read_reader(input.as_bytes()).unwrap(), input);
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(parse_numbers::<u32>(" 41 48  83 "), Ok(vec![41, 48, 83]));
+        assert_eq!(parse_numbers::<i32>("0 -3 6"), Ok(vec![0, -3, 6]));
+        assert!(parse_numbers::<u32>("1 x 3").is_err());
+    }
+}
@@ -0,0 +1,17 @@
+//! Helpers shared by every day of the Advent of Code 2023 solutions.
+
+pub mod answers;
+pub mod error;
+pub mod explain;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod numeric;
+pub mod puzzle;
+pub mod query;
+pub mod range_set;
+pub mod table;
+
+pub use error::{ParseError, SolveError};
+pub use puzzle::{Part, Puzzle};
@@ -0,0 +1,240 @@
+//! Number theory for the puzzles whose cycles line up: greatest common
+//! divisors, least common multiples, modular inverses and systems of
+//! congruences.
+
+use crate::numeric::{self, Int, Integer, Overflow};
+use std::{
+    fmt,
+    ops::{Div, Rem, Sub},
+};
+
+/// The integers [`gcd`] and [`lcm`] work on.
+pub trait Euclidean:
+    Integer + Ord + Div<Output = Self> + Rem<Output = Self> + Sub<Output = Self>
+{
+}
+
+impl<T> Euclidean for T where
+    T: Integer + Ord + Div<Output = Self> + Rem<Output = Self> + Sub<Output = Self>
+{
+}
+
+fn abs<T: Euclidean>(a: T) -> T {
+    if a < T::ZERO {
+        T::ZERO - a
+    } else {
+        a
+    }
+}
+
+/// The greatest common divisor of `a` and `b`, which is never negative, and
+/// 0 only if both are.
+pub fn gcd<T: Euclidean>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    abs(a)
+}
+
+/// The least common multiple of `a` and `b`, which is never negative, where
+/// `at` tells where it's taken in case it overflows.
+pub fn lcm<T, F>(a: T, b: T, at: F) -> Result<T, Overflow>
+where
+    T: Euclidean,
+    F: FnOnce() -> String,
+{
+    if a == T::ZERO || b == T::ZERO {
+        return Ok(T::ZERO);
+    }
+    // Dividing first only overflows if the multiple itself does
+    Ok(abs(numeric::mul(a / gcd(a, b), b, at)?))
+}
+
+/// The greatest common divisor of all of `items`, 0 if there are none.
+pub fn gcd_of<T, I>(items: I) -> T
+where
+    T: Euclidean,
+    I: IntoIterator<Item = T>,
+{
+    items.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The least common multiple of all of `items`, 1 if there are none, where
+/// `at` tells where the item of an index is in case taking it overflows.
+pub fn lcm_of<T, I, F>(items: I, at: F) -> Result<T, Overflow>
+where
+    T: Euclidean,
+    I: IntoIterator<Item = T>,
+    F: Fn(usize) -> String,
+{
+    items
+        .into_iter()
+        .enumerate()
+        .try_fold(T::ONE, |multiple, (i, item)| lcm(multiple, item, || at(i)))
+}
+
+/// The greatest common divisor `g` of `a` and `b`, with the `x` and `y` of
+/// Bézout's identity `a * x + b * y = g`, as `(g, x, y)`.
+pub fn extended_gcd(a: Int, b: Int) -> (Int, Int, Int) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x` congruent to 1, if `a` and the
+/// positive `modulus` are coprime.
+pub fn modinv(a: Int, modulus: Int) -> Option<Int> {
+    assert!(modulus > 0, "modulus {modulus} isn't positive");
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// `a + b` modulo `modulus`, for `a` and `b` in `0..modulus`, without
+/// overflowing.
+fn add_mod(a: Int, b: Int, modulus: Int) -> Int {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// `a * b` modulo `modulus`, for `a` and `b` in `0..modulus`, without
+/// overflowing.
+fn mul_mod(a: Int, b: Int, modulus: Int) -> Int {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    // Double and add, which stays below the modulus
+    let (mut a, mut b, mut product) = (a, b, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b >>= 1;
+    }
+    product
+}
+
+/// Why a system of congruences has no solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// The congruence of this index contradicts the ones before it.
+    Incompatible(usize),
+    /// The least common multiple of the moduli doesn't fit in an [`Int`].
+    Overflow,
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CrtError::Incompatible(i) => {
+                write!(f, "congruence {} contradicts the ones before it", i + 1)
+            }
+            CrtError::Overflow => write!(
+                f,
+                "the least common multiple of the moduli overflows {}",
+                Int::NAME
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// The `x` congruent to every `(residue, modulus)` of `congruences`, with the
+/// Chinese remainder theorem, as `(x, m)` where `m` is the least common
+/// multiple of the moduli and `x` is in `0..m`. The moduli must be positive,
+/// but don't need to be coprime.
+pub fn crt(congruences: &[(Int, Int)]) -> Result<(Int, Int), CrtError> {
+    let (mut x, mut m): (Int, Int) = (0, 1);
+    for (i, &(residue, modulus)) in congruences.iter().enumerate() {
+        assert!(modulus > 0, "modulus {modulus} isn't positive");
+        let residue = residue.rem_euclid(modulus);
+        // x + m * t is congruent to the residue for the t solving
+        // m * t = residue - x modulo the modulus
+        let g = gcd(m, modulus);
+        let difference = residue - x.rem_euclid(modulus);
+        if difference % g != 0 {
+            return Err(CrtError::Incompatible(i));
+        }
+        let reduced = modulus / g;
+        let inverse = modinv(m / g, reduced).expect("m / g and modulus / g are coprime");
+        let t = mul_mod((difference / g).rem_euclid(reduced), inverse, reduced);
+        let multiple = m.checked_mul(reduced).ok_or(CrtError::Overflow)?;
+        // t < reduced, so this stays below the new multiple
+        x += m * t;
+        m = multiple;
+    }
+    Ok((x, m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        let at = |i: usize| format!("item {i}");
+        assert_eq!(gcd(48_u64, 18), 6);
+        assert_eq!(gcd(-48_i64, 18), 6);
+        assert_eq!(gcd(0_u32, 0), 0);
+        assert_eq!(lcm(4_u64, 6, || "here".to_string()), Ok(12));
+        assert_eq!(lcm(-4_i32, 6, || "here".to_string()), Ok(12));
+        assert_eq!(lcm(0_u32, 6, || "here".to_string()), Ok(0));
+        assert_eq!(gcd_of([12_u64, 18, 30]), 6);
+        assert_eq!(lcm_of([2_u64, 3, 4, 5], at), Ok(60));
+        assert_eq!(lcm_of(Vec::<u64>::new(), at), Ok(1));
+        // Large factors shared by every item don't overflow
+        assert_eq!(lcm_of([u64::MAX, u64::MAX], at), Ok(u64::MAX));
+        assert_eq!(
+            lcm_of([u64::MAX, u64::MAX - 1], at).unwrap_err().at,
+            "item 1"
+        );
+    }
+
+    #[test]
+    fn test_modinv() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(-6, 4), (2, -1, -1));
+        assert_eq!(modinv(3, 11), Some(4));
+        assert_eq!(modinv(-3, 11), Some(7));
+        assert_eq!(modinv(6, 9), None);
+        assert_eq!(modinv(5, 1), Some(0));
+        assert_eq!(mul_mod(Int::MAX - 1, Int::MAX - 2, Int::MAX), 2);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Ok((10, 12)));
+        assert_eq!(crt(&[(-1, 4), (7, 8)]), Ok((7, 8)));
+        assert_eq!(crt(&[]), Ok((0, 1)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), Err(CrtError::Incompatible(1)));
+        let prime = 2_147_483_647;
+        assert_eq!(
+            crt(&[(1, prime), (2, prime - 1)]).map(|(x, m)| (x % prime, x % (prime - 1), m)),
+            Ok((1, 2, prime * (prime - 1)))
+        );
+        assert_eq!(
+            crt(&[(0, Int::MAX), (0, Int::MAX - 1)]),
+            Err(CrtError::Overflow)
+        );
+        assert_eq!(
+            CrtError::Incompatible(1).to_string(),
+            "congruence 2 contradicts the ones before it"
+        );
+    }
+}
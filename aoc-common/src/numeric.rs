@@ -0,0 +1,208 @@
+//! The arithmetic the days accumulate their answers with, which notices when
+//! an answer outgrows its type instead of silently wrapping around.
+//!
+//! The accumulators are [`Uint`] and [`Int`], which are 64 bits wide, or 128
+//! bits wide with the `wide` feature. An operation that overflows returns
+//! where it did as an [`Overflow`], which converts into a
+//! [`SolveError`](crate::SolveError) for the parts to return with `?`.
+
+use std::fmt;
+
+/// The type unsigned answers are accumulated in.
+#[cfg(not(feature = "wide"))]
+pub type Uint = u64;
+/// The type unsigned answers are accumulated in.
+#[cfg(feature = "wide")]
+pub type Uint = u128;
+
+/// The type signed answers are accumulated in.
+#[cfg(not(feature = "wide"))]
+pub type Int = i64;
+/// The type signed answers are accumulated in.
+#[cfg(feature = "wide")]
+pub type Int = i128;
+
+/// The integer types the operations of this module work on.
+pub trait Integer: Copy + fmt::Display {
+    const ZERO: Self;
+    const ONE: Self;
+    /// The name of the type, for reports.
+    const NAME: &'static str;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_pow(self, exponent: u32) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const NAME: &'static str = stringify!($t);
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_pow(self, exponent: u32) -> Option<Self> {
+                <$t>::checked_pow(self, exponent)
+            }
+        })*
+    };
+}
+
+impl_integer!(u32, u64, u128, usize, i32, i64, i128);
+
+/// An operation whose result didn't fit its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    /// Where the operation happened, such as `card 187`.
+    pub at: String,
+    /// The operation, such as `4294967295 + 1`.
+    pub operation: String,
+    /// The type of the operation.
+    pub r#type: &'static str,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} overflows {}",
+            self.at, self.operation, self.r#type
+        )
+    }
+}
+
+/// `result`, or the overflow of `operation` at `at` if there is none.
+fn checked<T, F>(result: Option<T>, at: F, operation: fmt::Arguments) -> Result<T, Overflow>
+where
+    T: Integer,
+    F: FnOnce() -> String,
+{
+    result.ok_or_else(|| Overflow {
+        at: at(),
+        operation: operation.to_string(),
+        r#type: T::NAME,
+    })
+}
+
+/// `a + b`, where `at` tells where the addition happens in case it
+/// overflows.
+pub fn add<T, F>(a: T, b: T, at: F) -> Result<T, Overflow>
+where
+    T: Integer,
+    F: FnOnce() -> String,
+{
+    checked(a.checked_add(b), at, format_args!("{a} + {b}"))
+}
+
+pub fn sub<T, F>(a: T, b: T, at: F) -> Result<T, Overflow>
+where
+    T: Integer,
+    F: FnOnce() -> String,
+{
+    checked(a.checked_sub(b), at, format_args!("{a} - {b}"))
+}
+
+pub fn mul<T, F>(a: T, b: T, at: F) -> Result<T, Overflow>
+where
+    T: Integer,
+    F: FnOnce() -> String,
+{
+    checked(a.checked_mul(b), at, format_args!("{a} * {b}"))
+}
+
+pub fn pow<T, F>(base: T, exponent: u32, at: F) -> Result<T, Overflow>
+where
+    T: Integer,
+    F: FnOnce() -> String,
+{
+    checked(
+        base.checked_pow(exponent),
+        at,
+        format_args!("{base} ^ {exponent}"),
+    )
+}
+
+/// `value` as a `U`, where `at` tells what it is in case it doesn't fit.
+pub fn convert<T, U, F>(value: T, at: F) -> Result<U, Overflow>
+where
+    T: TryInto<U> + Copy + fmt::Display,
+    U: Integer,
+    F: FnOnce() -> String,
+{
+    checked(value.try_into().ok(), at, format_args!("{value}"))
+}
+
+/// The sum of `items`, where `at` tells where the item of an index is in case
+/// adding it overflows.
+pub fn sum<T, I, F>(items: I, at: F) -> Result<T, Overflow>
+where
+    T: Integer,
+    I: IntoIterator<Item = T>,
+    F: Fn(usize) -> String,
+{
+    items
+        .into_iter()
+        .enumerate()
+        .try_fold(T::ZERO, |total, (i, item)| add(total, item, || at(i)))
+}
+
+/// The product of `items`, where `at` tells where the item of an index is in
+/// case multiplying by it overflows.
+pub fn product<T, I, F>(items: I, at: F) -> Result<T, Overflow>
+where
+    T: Integer,
+    I: IntoIterator<Item = T>,
+    F: Fn(usize) -> String,
+{
+    items
+        .into_iter()
+        .enumerate()
+        .try_fold(T::ONE, |total, (i, item)| mul(total, item, || at(i)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overflows() {
+        let line = |i: usize| format!("line {}", i + 1);
+        assert_eq!(sum([1_u32, 2, 3], line), Ok(6));
+        assert_eq!(
+            sum([u32::MAX - 1, 1, 2, 3], line),
+            Err(Overflow {
+                at: "line 3".to_string(),
+                operation: "4294967295 + 2".to_string(),
+                r#type: "u32",
+            })
+        );
+        assert_eq!(
+            product([1_i64 << 62, 2, 4], line).unwrap_err().to_string(),
+            "line 2: 4611686018427387904 * 2 overflows i64"
+        );
+        assert_eq!(product([3_i64, 4], line), Ok(12));
+        assert_eq!(pow(2_u32, 31, || "card 1".to_string()), Ok(1 << 31));
+        assert!(pow(2_u32, 32, || "card 1".to_string()).is_err());
+        assert!(sub(0_u64, 1, || "here".to_string()).is_err());
+        assert_eq!(
+            convert::<_, u32, _>(1_u64 << 32, || "seed 1".to_string())
+                .unwrap_err()
+                .to_string(),
+            "seed 1: 4294967296 overflows u32"
+        );
+        assert_eq!(convert::<_, u32, _>(7_u64, || "seed 1".to_string()), Ok(7));
+    }
+}
@@ -0,0 +1,176 @@
+//! The interface every day implements, so that inputs are parsed once and
+//! both parts can be solved from the parsed model.
+
+use crate::{
+    error::{ParseError, SolveError},
+    explain::Explanation,
+    input::{load_input, read_reader},
+    query::{Query, Reply},
+};
+use std::{fmt, io::BufRead, marker::PhantomData, path::Path};
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub trait Puzzle {
+    /// The parsed puzzle input both parts are solved from.
+    type Input;
+    /// The answer of either part.
+    type Output: fmt::Display;
+
+    /// The version of the solver. Bump it whenever a change could alter the
+    /// answers, so that answers cached by the runner are solved again.
+    const VERSION: u32 = 1;
+
+    /// The queries [`Puzzle::query`] answers.
+    const QUERIES: &'static [Query] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Output, SolveError>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Output, SolveError>;
+
+    /// Parse the puzzle input found at `filename`, which is the standard
+    /// input if it's `-`.
+    fn parse_file<P>(filename: P) -> Result<Self::Input, ParseError>
+    where
+        P: AsRef<Path>,
+    {
+        parse_file_with(filename.as_ref(), Self::parse)
+    }
+
+    fn parse_reader<R>(reader: R) -> Result<Self::Input, ParseError>
+    where
+        R: BufRead,
+    {
+        parse_reader_with(reader, Self::parse)
+    }
+
+    fn solve(input: &Self::Input, part: Part) -> Result<Self::Output, SolveError> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Break the answer of `part` down into what every item of the input
+    /// contributed to it, if the day knows how to.
+    fn explain(_input: &Self::Input, _part: Part) -> Result<Option<Explanation>, SolveError> {
+        Ok(None)
+    }
+
+    /// Answer the query made of `words`, such as `["location", "79"]`, about
+    /// the input, or `None` if it isn't one of the day's [`Puzzle::QUERIES`].
+    fn query(_input: &Self::Input, _words: &[&str]) -> Option<Reply> {
+        None
+    }
+}
+
+/// A day's puzzle input, parsed and ready to solve either part, for code that
+/// works with any day.
+pub trait Solution {
+    fn solve(&self, part: Part) -> Result<String, SolveError>;
+
+    /// The explanation of `part`, if the day has one, whose total is known
+    /// not to overflow.
+    fn explain(&self, part: Part) -> Result<Option<Explanation>, SolveError>;
+
+    fn query(&self, words: &[&str]) -> Option<Reply>;
+}
+
+struct Parsed<P: Puzzle> {
+    input: P::Input,
+    puzzle: PhantomData<fn() -> P>,
+}
+
+impl<P: Puzzle> Solution for Parsed<P> {
+    fn solve(&self, part: Part) -> Result<String, SolveError> {
+        P::solve(&self.input, part).map(|answer| answer.to_string())
+    }
+
+    fn explain(&self, part: Part) -> Result<Option<Explanation>, SolveError> {
+        let explanation = P::explain(&self.input, part)?;
+        if let Some(explanation) = &explanation {
+            explanation.total()?;
+        }
+        Ok(explanation)
+    }
+
+    fn query(&self, words: &[&str]) -> Option<Reply> {
+        P::query(&self.input, words)
+    }
+}
+
+/// Parses a day's puzzle input into a [`Solution`].
+pub type Parser = fn(&str) -> Result<Box<dyn Solution>, ParseError>;
+
+/// The [`Parser`] of the puzzle `P`.
+pub fn parse_solution<P>(input: &str) -> Result<Box<dyn Solution>, ParseError>
+where
+    P: Puzzle + 'static,
+{
+    Ok(Box::new(Parsed::<P> {
+        input: P::parse(input)?,
+        puzzle: PhantomData,
+    }))
+}
+
+/// Parse the puzzle input found at `filename` with `parser`. The filename `-`
+/// stands for the standard input.
+pub fn parse_solution_file(
+    parser: Parser,
+    filename: &Path,
+) -> Result<Box<dyn Solution>, ParseError> {
+    parse_file_with(filename, parser)
+}
+
+/// Parse the puzzle input read from `reader` with `parser`.
+pub fn parse_solution_reader<R>(parser: Parser, reader: R) -> Result<Box<dyn Solution>, ParseError>
+where
+    R: BufRead,
+{
+    parse_reader_with(reader, parser)
+}
+
+fn parse_file_with<T, F>(filename: &Path, parse: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&str) -> Result<T, ParseError>,
+{
+    let input = load_input(filename)?;
+    parse(&input).map_err(|error| error.with_file(filename))
+}
+
+fn parse_reader_with<T, R, F>(reader: R, parse: F) -> Result<T, ParseError>
+where
+    R: BufRead,
+    F: FnOnce(&str) -> Result<T, ParseError>,
+{
+    let input = read_reader(reader).map_err(|source| ParseError::Io { file: None, source })?;
+    parse(&input)
+}
@@ -0,0 +1,51 @@
+//! Questions about a parsed input, such as `location 79`, that days answer in
+//! the runner's REPL.
+
+use std::str::FromStr;
+
+/// A query a day answers, for the REPL's help.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Query {
+    /// How the query is written, such as `location <seed>`.
+    pub usage: &'static str,
+    pub help: &'static str,
+}
+
+/// The answer to a query, or why it can't be answered.
+pub type Reply = Result<String, String>;
+
+/// The number written as `text` in a query.
+pub fn number<T>(text: &str) -> Result<T, String>
+where
+    T: FromStr,
+{
+    text.parse()
+        .map_err(|_| format!("expected a number, found `{text}`"))
+}
+
+/// The item `number` of `items`, counting from 1 like the puzzles do.
+pub fn nth<'a, T>(items: &'a [T], number: &str, name: &str) -> Result<&'a T, String> {
+    let n: usize = self::number(number)?;
+    n.checked_sub(1)
+        .and_then(|i| items.get(i))
+        .ok_or_else(|| format!("there's no {name} {n}, only {} of them", items.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nth() {
+        let races = [7, 15, 30];
+        assert_eq!(nth(&races, "2", "race"), Ok(&15));
+        assert_eq!(
+            nth(&races, "0", "race"),
+            Err("there's no race 0, only 3 of them".to_string())
+        );
+        assert_eq!(
+            nth(&races, "x", "race"),
+            Err("expected a number, found `x`".to_string())
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Sets of numbers kept as the ranges they cover, for the puzzles that move
+//! whole ranges around rather than one number at a time.
+
+use std::{
+    ops::{Add, Range, Sub},
+    slice,
+};
+
+/// A set of numbers, as sorted ranges that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    pub fn new() -> Self {
+        RangeSet { ranges: vec![] }
+    }
+
+    /// The set of the numbers of any of `ranges`, which may be empty, overlap
+    /// or come in any order.
+    fn normalized(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_unstable_by_key(|range| range.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        RangeSet { ranges: merged }
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = Self::normalized(ranges);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    /// The lowest number of the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    /// The disjoint ranges of the set, from the lowest.
+    pub fn iter(&self) -> slice::Iter<'_, Range<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalized(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // Whichever ends first can't overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        // The overlaps are as far apart as the ranges they come from
+        RangeSet { ranges }
+    }
+
+    /// The numbers of this set that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for range in &self.ranges {
+            while other.ranges.get(j).is_some_and(|o| o.end <= range.start) {
+                j += 1;
+            }
+            let mut start = range.start;
+            while let Some(o) = other.ranges.get(j).filter(|o| o.start < range.end) {
+                if start < o.start {
+                    ranges.push(start..o.start);
+                }
+                start = start.max(o.end);
+                if o.end > range.end {
+                    // It may overlap the next range as well
+                    break;
+                }
+                j += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// The set moved so that `from` lands on `to`, which every number must
+    /// stay in the type while doing.
+    pub fn shift(&self, from: T, to: T) -> Self
+    where
+        T: Add<Output = T> + Sub<Output = T>,
+    {
+        let shift = |value: T| {
+            if to >= from {
+                value + (to - from)
+            } else {
+                value - (from - to)
+            }
+        };
+        RangeSet {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|range| shift(range.start)..shift(range.end))
+                .collect(),
+        }
+    }
+}
+
+impl<T> Default for RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Range<T>> for RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    fn from(range: Range<T>) -> Self {
+        Self::normalized(vec![range])
+    }
+}
+
+impl<T> FromIterator<Range<T>> for RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        Self::normalized(ranges.into_iter().collect())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RangeSet<T>
+where
+    T: Copy + Ord,
+{
+    type Item = &'a Range<T>;
+    type IntoIter = slice::Iter<'a, Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+
+    fn ranges(set: &RangeSet<u32>) -> Vec<Range<u32>> {
+        set.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_operations() {
+        let a: RangeSet<u32> = [5..8, 0..3, 2..4, 8..10, 12..12].into_iter().collect();
+        assert_eq!(ranges(&a), [0..4, 5..10]);
+        assert!(a.contains(3) && a.contains(9));
+        assert!(!a.contains(4) && !a.contains(10));
+        assert_eq!(a.min(), Some(0));
+        let b: RangeSet<u32> = [3..6, 9..20].into_iter().collect();
+        assert_eq!(a.union(&b), RangeSet::from(0..20));
+        assert_eq!(ranges(&a.intersection(&b)), [3..4, 5..6, 9..10]);
+        assert_eq!(ranges(&a.difference(&b)), [0..3, 6..9]);
+        assert_eq!(ranges(&b.difference(&a)), [4..5, 10..20]);
+        assert_eq!(ranges(&a.shift(5, 105)), [100..104, 105..110]);
+        assert_eq!(ranges(&b.shift(5, 2)), [0..3, 6..17]);
+        let mut c = RangeSet::new();
+        assert_eq!(c.min(), None);
+        c.insert(4..5);
+        assert_eq!(c.union(&a), RangeSet::from(0..10));
+    }
+
+    #[test]
+    fn test_against_bits() {
+        // Sets of numbers below 64 against the bits of a u64
+        let random = |rng: &mut Rng| {
+            let mut set = RangeSet::new();
+            let mut bits = 0_u64;
+            for _ in 0..rng.below(5) {
+                let start = rng.below(64) as u32;
+                let end = (start + rng.below(20) as u32).min(64);
+                set.insert(start..end);
+                for i in start..end {
+                    bits |= 1 << i;
+                }
+            }
+            (set, bits)
+        };
+        let bits_of = |set: &RangeSet<u32>| {
+            let mut bits = 0_u64;
+            for range in set {
+                for i in range.clone() {
+                    bits |= 1 << i;
+                }
+            }
+            assert!(
+                set.ranges
+                    .windows(2)
+                    .all(|pair| pair[0].end < pair[1].start),
+                "{set:?}"
+            );
+            bits
+        };
+        let mut rng = Rng::new(25);
+        for _ in 0..1000 {
+            let (a, a_bits) = random(&mut rng);
+            let (b, b_bits) = random(&mut rng);
+            assert_eq!(bits_of(&a), a_bits);
+            assert_eq!(bits_of(&a.union(&b)), a_bits | b_bits);
+            assert_eq!(bits_of(&a.intersection(&b)), a_bits & b_bits);
+            assert_eq!(bits_of(&a.difference(&b)), a_bits & !b_bits);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Plain-text tables for the reports printed by the runner.
+
+/// Lay out `rows` in columns separated by two spaces, the first row being the
+/// header. Every row must have the same number of cells.
+pub fn format_table(rows: &[Vec<String>]) -> String {
+    let mut widths = vec![0; rows.first().map_or(0, Vec::len)];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut table = String::new();
+    for row in rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_table() {
+        let rows = vec![
+            vec!["Input".to_string(), "Part 1".to_string(), "".to_string()],
+            vec!["alice.txt".to_string(), "35".to_string(), "".to_string()],
+        ];
+        assert_eq!(format_table(&rows), "Input      Part 1\nalice.txt  35\n");
+    }
+}
@@ -0,0 +1,683 @@
+use crate::trace;
+use aoc_common::Part;
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run --day <N> [--part <1|2>] [--input <PATH>] [--format <text|json>] [--no-cache]
+            [--cache-dir <DIR>] [--trace <FILTER>] [--explain] [--checked]
+    aoc run --all [--part <1|2>] [--format <text|json>] [--jobs <N>] [--no-cache]
+            [--cache-dir <DIR>] [--trace <FILTER>] [--explain] [--checked]
+    aoc verify [--day <N>]
+    aoc batch --dir <DIR> [--day <N>] [--format <table|csv>]
+    aoc bench [--day <N>] [--input <PATH>] [--runs <N>] [--save <NAME>] [--baseline <NAME>]
+    aoc bench [--day <N>] --size <N> [--seed <N>] [--runs <N>] [--save <NAME>] [--baseline <NAME>]
+    aoc generate --day <N> --size <N> [--seed <N>]
+    aoc watch --day <N>
+    aoc repl --day <N> [--input <PATH>]
+    aoc report [--out <DIR>] [--jobs <N>]
+    aoc new --day <N>
+    aoc cache clear [--cache-dir <DIR>]
+    aoc help
+
+Options:
+    --day <N>        Day to run
+    --all            Run every day against its own input
+    --part <1|2>     Only run the given part (both parts by default)
+    --input <PATH>   Input to run the day against (data/input.txt by default),
+                     or `-` to read it from the standard input
+    --format <text|json>
+                     Print the answers as text (default) or as a JSON array
+                     of one object per part, with the fields `day`, `part`,
+                     `input`, `answer`, `error` or, with `--checked`,
+                     `overflow`, `elapsed_ms` and `cached`. `answer` is a number when
+                     it's an integer and a string otherwise, `elapsed_ms` is a
+                     number, and an input that can't be parsed gets an object
+                     with only `day`, `input` and `error`
+    --jobs <N>       Run the days on N threads and print a timing summary
+    --no-cache       Solve every part, even if its answer is cached
+    --cache-dir <DIR>
+                     Directory to cache answers in ($XDG_CACHE_HOME/aoc, or
+                     ~/.cache/aoc, by default)
+    --trace <FILTER> Print what the solvers trace to the standard error, such
+                     as `debug`, `day5` or `info,day8=trace`
+    --explain        Show what every item of the input contributed to the answers
+    --checked        Report the parts that overflow as overflows, apart from
+                     other errors, solving every part again
+    --size <N>       Size of the synthetic input, whose meaning depends on the day
+    --seed <N>       Seed of the synthetic input (2023 by default)
+    --runs <N>       Times to repeat each benchmark (10 by default)
+    --save <NAME>    Save the benchmark medians as the baseline NAME
+    --baseline <NAME>
+                     Compare the benchmarks against the baseline NAME
+    --out <DIR>      Directory to write the reports to (target/aoc-report by
+                     default)
+
+`aoc verify` checks the answers registered in each day's data/answers.txt,
+for every day unless `--day` is given.
+
+`aoc batch` runs the day against every file in DIR, or, without `--day`, every
+day against the files in DIR/day<N> for the days that have one.
+
+`aoc run` caches answers in the cache directory, keyed by the day, the part,
+the solver version, the width of the answers and the hash and length of the
+input. `aoc cache clear` removes the cached answers from it.
+`--trace` and `--checked` solve every part again, as cached answers have
+nothing to trace or check. A part whose answer overflows fails either way;
+build with `--features wide` to accumulate the answers in 128 bits when they
+overflow 64.
+
+`aoc generate` prints synthetic input for the day, which `aoc run --input -`
+can read, and `aoc bench --size` benchmarks the days against such inputs.
+
+`aoc watch` runs the day against every file in its data directory, and again
+against every file that changes, showing how the answers changed.
+
+`aoc repl` parses the day's input once and reads commands such as `part 1` or
+the day's own queries, like `location 79` or `hand KTJJT`. `help` lists them.
+
+`aoc report` runs every day against its own input and writes report.md and
+report.html, with the answers, timings and input sizes, and whether the answers
+match the registered ones.
+
+`aoc new` generates the crate of a new day, with placeholder inputs, an empty
+answer registry and tests that are ignored until answers are registered, and
+adds it to the workspace and the runner.
+
+`aoc bench` times parsing and each part separately, for every day unless
+`--day` is given. Baselines are kept in target/aoc-bench.";
+
+/// How many times each benchmark runs unless `--runs` is given.
+const DEFAULT_RUNS: usize = 10;
+
+/// The seed of synthetic inputs unless `--seed` is given.
+const DEFAULT_SEED: u64 = 2023;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Batch(BatchArgs),
+    Bench(BenchArgs),
+    Generate(GenerateArgs),
+    Watch(WatchArgs),
+    Repl(ReplArgs),
+    Report(ReportArgs),
+    New(NewArgs),
+    ClearCache(CacheArgs),
+    Help,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    /// The day to run, or `None` to run all of them.
+    pub day: Option<u8>,
+    pub parts: Vec<Part>,
+    pub input: Option<PathBuf>,
+    pub format: Format,
+    /// How many days to run at once, if they're to be run in parallel.
+    pub jobs: Option<usize>,
+    pub use_cache: bool,
+    /// The directory to cache answers in, instead of the default one.
+    pub cache_dir: Option<PathBuf>,
+    /// The filter of the spans and events to print, if they're to be traced.
+    pub trace: Option<String>,
+    pub explain: bool,
+    /// Whether to report the parts that overflow apart from other errors.
+    pub checked: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyArgs {
+    /// The day to verify, or `None` to verify all of them.
+    pub day: Option<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchFormat {
+    Table,
+    Csv,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BatchArgs {
+    pub dir: PathBuf,
+    /// The day to run, or `None` to run all of them.
+    pub day: Option<u8>,
+    pub format: BatchFormat,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BenchArgs {
+    /// The day to benchmark, or `None` to benchmark all of them.
+    pub day: Option<u8>,
+    pub input: Option<PathBuf>,
+    /// The size of the synthetic inputs to use instead of the real ones.
+    pub size: Option<usize>,
+    pub seed: u64,
+    pub runs: usize,
+    /// The baseline to save the results as.
+    pub save: Option<String>,
+    /// The baseline to compare the results against.
+    pub baseline: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GenerateArgs {
+    pub day: u8,
+    pub size: usize,
+    pub seed: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct WatchArgs {
+    pub day: u8,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReplArgs {
+    pub day: u8,
+    pub input: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReportArgs {
+    /// The directory to write the reports to.
+    pub out: Option<PathBuf>,
+    /// How many days to run at once, if they're to be run in parallel.
+    pub jobs: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NewArgs {
+    pub day: u8,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CacheArgs {
+    /// The directory answers are cached in, instead of the default one.
+    pub cache_dir: Option<PathBuf>,
+}
+
+pub fn parse<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => parse_run(args).map(Command::Run),
+        Some("verify") => parse_verify(args).map(Command::Verify),
+        Some("batch") => parse_batch(args).map(Command::Batch),
+        Some("bench") => parse_bench(args).map(Command::Bench),
+        Some("generate") => parse_generate(args).map(Command::Generate),
+        Some("watch") => parse_required_day(args).map(|day| Command::Watch(WatchArgs { day })),
+        Some("repl") => parse_repl(args).map(Command::Repl),
+        Some("report") => parse_report(args).map(Command::Report),
+        Some("new") => parse_required_day(args).map(|day| Command::New(NewArgs { day })),
+        Some("cache") => match args.next().as_deref() {
+            Some("clear") => parse_cache(args).map(Command::ClearCache),
+            Some(other) => Err(format!("unknown cache command `{other}`")),
+            None => Err("`cache` expects a command".to_string()),
+        },
+        Some("help") | Some("--help") | Some("-h") | None => Ok(Command::Help),
+        Some(other) => Err(format!("unknown command `{other}`")),
+    }
+}
+
+fn parse_run<I>(mut args: I) -> Result<RunArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut all = false;
+    let mut parts = Part::ALL.to_vec();
+    let mut input = None;
+    let mut format = Format::Text;
+    let mut jobs = None;
+    let mut use_cache = true;
+    let mut cache_dir = None;
+    let mut trace = None;
+    let mut explain = false;
+    let mut checked = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(&arg, args.next())?),
+            "--all" => all = true,
+            "--part" => {
+                let number = parse_number(&arg, args.next())?;
+                let part = Part::from_number(number)
+                    .ok_or_else(|| format!("`--part` must be 1 or 2, got {number}"))?;
+                parts = vec![part];
+            }
+            "--input" => input = Some(PathBuf::from(value(&arg, args.next())?)),
+            "--jobs" => jobs = Some(parse_number(&arg, args.next())?),
+            "--no-cache" => use_cache = false,
+            "--cache-dir" => cache_dir = Some(PathBuf::from(value(&arg, args.next())?)),
+            "--trace" => {
+                let filter = value(&arg, args.next())?;
+                trace::parse_filter(&filter)?;
+                trace = Some(filter);
+            }
+            "--explain" => explain = true,
+            "--checked" => checked = true,
+            "--format" => {
+                format = match value(&arg, args.next())?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => return Err(format!("`--format` must be text or json, got `{other}`")),
+                }
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    match (day, all) {
+        (Some(_), true) => Err("`--day` and `--all` can't be used together".to_string()),
+        (None, false) => Err("either `--day` or `--all` is required".to_string()),
+        (None, true) if input.is_some() => {
+            Err("`--input` can only be used with `--day`".to_string())
+        }
+        _ if jobs == Some(0) => Err("`--jobs` must be at least 1".to_string()),
+        _ if explain && (jobs.is_some() || format == Format::Json) => {
+            Err("`--explain` can't be used with `--jobs` or `--format json`".to_string())
+        }
+        _ => Ok(RunArgs {
+            day,
+            parts,
+            input,
+            format,
+            jobs,
+            use_cache,
+            cache_dir,
+            trace,
+            explain,
+            checked,
+        }),
+    }
+}
+
+fn parse_verify<I>(mut args: I) -> Result<VerifyArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    Ok(VerifyArgs { day })
+}
+
+fn parse_batch<I>(mut args: I) -> Result<BatchArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut dir = None;
+    let mut day = None;
+    let mut format = BatchFormat::Table;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dir" => dir = Some(PathBuf::from(value(&arg, args.next())?)),
+            "--day" => day = Some(parse_number(&arg, args.next())?),
+            "--format" => {
+                format = match value(&arg, args.next())?.as_str() {
+                    "table" => BatchFormat::Table,
+                    "csv" => BatchFormat::Csv,
+                    other => return Err(format!("`--format` must be table or csv, got `{other}`")),
+                }
+            }
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    let dir = dir.ok_or_else(|| "`--dir` is required".to_string())?;
+    Ok(BatchArgs { dir, day, format })
+}
+
+fn parse_bench<I>(mut args: I) -> Result<BenchArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut bench = BenchArgs {
+        day: None,
+        input: None,
+        size: None,
+        seed: DEFAULT_SEED,
+        runs: DEFAULT_RUNS,
+        save: None,
+        baseline: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => bench.day = Some(parse_number(&arg, args.next())?),
+            "--input" => bench.input = Some(PathBuf::from(value(&arg, args.next())?)),
+            "--size" => bench.size = Some(parse_number(&arg, args.next())?),
+            "--seed" => bench.seed = parse_number(&arg, args.next())?,
+            "--runs" => bench.runs = parse_number(&arg, args.next())?,
+            "--save" => bench.save = Some(value(&arg, args.next())?),
+            "--baseline" => bench.baseline = Some(value(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    if bench.runs == 0 {
+        Err("`--runs` must be at least 1".to_string())
+    } else if bench.day.is_none() && bench.input.is_some() {
+        Err("`--input` can only be used with `--day`".to_string())
+    } else if bench.size.is_some() && bench.input.is_some() {
+        Err("`--input` and `--size` can't be used together".to_string())
+    } else {
+        Ok(bench)
+    }
+}
+
+fn parse_generate<I>(mut args: I) -> Result<GenerateArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut size = None;
+    let mut seed = DEFAULT_SEED;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(&arg, args.next())?),
+            "--size" => size = Some(parse_number(&arg, args.next())?),
+            "--seed" => seed = parse_number(&arg, args.next())?,
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    Ok(GenerateArgs {
+        day: day.ok_or("`--day` is required")?,
+        size: size.ok_or("`--size` is required")?,
+        seed,
+    })
+}
+
+fn parse_repl<I>(mut args: I) -> Result<ReplArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(&arg, args.next())?),
+            "--input" => input = Some(PathBuf::from(value(&arg, args.next())?)),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    if input.as_deref() == Some(Path::new("-")) {
+        // The commands are read from there
+        return Err("`aoc repl` can't read the input from the standard input".to_string());
+    }
+    Ok(ReplArgs {
+        day: day.ok_or("`--day` is required")?,
+        input,
+    })
+}
+
+fn parse_report<I>(mut args: I) -> Result<ReportArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut report = ReportArgs {
+        out: None,
+        jobs: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => report.out = Some(PathBuf::from(value(&arg, args.next())?)),
+            "--jobs" => report.jobs = Some(parse_number(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    if report.jobs == Some(0) {
+        Err("`--jobs` must be at least 1".to_string())
+    } else {
+        Ok(report)
+    }
+}
+
+/// The arguments of commands that only take a `--day`, which is required.
+fn parse_required_day<I>(mut args: I) -> Result<u8, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(&arg, args.next())?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    day.ok_or_else(|| "`--day` is required".to_string())
+}
+
+fn parse_cache<I>(mut args: I) -> Result<CacheArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut cache_dir = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--cache-dir" => cache_dir = Some(PathBuf::from(value(&arg, args.next())?)),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    Ok(CacheArgs { cache_dir })
+}
+
+fn value(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("`{flag}` expects a value"))
+}
+
+fn parse_number<T>(flag: &str, number: Option<String>) -> Result<T, String>
+where
+    T: FromStr,
+{
+    let number = value(flag, number)?;
+    number
+        .parse::<T>()
+        .map_err(|_| format!("`{flag}` expects a number, got `{number}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(args: &str) -> Result<Command, String> {
+        parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_run_day() {
+        assert_eq!(
+            parse_str("run --day 5 --part 2 --input data/example.txt --trace day5=debug --explain --checked"),
+            Ok(Command::Run(RunArgs {
+                day: Some(5),
+                parts: vec![Part::Two],
+                input: Some(PathBuf::from("data/example.txt")),
+                format: Format::Text,
+                jobs: None,
+                use_cache: true,
+                cache_dir: None,
+                trace: Some("day5=debug".to_string()),
+                explain: true,
+                checked: true,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_run_all() {
+        assert_eq!(
+            parse_str("run --all --format json --jobs 4 --no-cache --cache-dir /tmp/aoc"),
+            Ok(Command::Run(RunArgs {
+                day: None,
+                parts: vec![Part::One, Part::Two],
+                input: None,
+                format: Format::Json,
+                jobs: Some(4),
+                use_cache: false,
+                cache_dir: Some(PathBuf::from("/tmp/aoc")),
+                trace: None,
+                explain: false,
+                checked: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(
+            parse_str("verify"),
+            Ok(Command::Verify(VerifyArgs { day: None }))
+        );
+        assert_eq!(
+            parse_str("verify --day 8"),
+            Ok(Command::Verify(VerifyArgs { day: Some(8) }))
+        );
+    }
+
+    #[test]
+    fn test_batch() {
+        assert_eq!(
+            parse_str("batch --dir inputs --day 3 --format csv"),
+            Ok(Command::Batch(BatchArgs {
+                dir: PathBuf::from("inputs"),
+                day: Some(3),
+                format: BatchFormat::Csv,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_bench() {
+        assert_eq!(
+            parse_str("bench --day 5 --runs 3 --baseline before --save after"),
+            Ok(Command::Bench(BenchArgs {
+                day: Some(5),
+                input: None,
+                size: None,
+                seed: DEFAULT_SEED,
+                runs: 3,
+                save: Some("after".to_string()),
+                baseline: Some("before".to_string()),
+            }))
+        );
+        assert_eq!(
+            parse_str("bench --size 1000 --seed 7"),
+            Ok(Command::Bench(BenchArgs {
+                day: None,
+                input: None,
+                size: Some(1000),
+                seed: 7,
+                runs: DEFAULT_RUNS,
+                save: None,
+                baseline: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            parse_str("generate --day 8 --size 500"),
+            Ok(Command::Generate(GenerateArgs {
+                day: 8,
+                size: 500,
+                seed: DEFAULT_SEED,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_watch() {
+        assert_eq!(
+            parse_str("watch --day 3"),
+            Ok(Command::Watch(WatchArgs { day: 3 }))
+        );
+    }
+
+    #[test]
+    fn test_repl() {
+        assert_eq!(
+            parse_str("repl --day 5 --input data/example.txt"),
+            Ok(Command::Repl(ReplArgs {
+                day: 5,
+                input: Some(PathBuf::from("data/example.txt")),
+            }))
+        );
+        assert!(parse_str("repl --input data/example.txt").is_err());
+        assert!(parse_str("repl --day 5 --input -").is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        assert_eq!(
+            parse_str("report --out wiki --jobs 4"),
+            Ok(Command::Report(ReportArgs {
+                out: Some(PathBuf::from("wiki")),
+                jobs: Some(4),
+            }))
+        );
+        assert!(parse_str("report --jobs 0").is_err());
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            parse_str("new --day 10"),
+            Ok(Command::New(NewArgs { day: 10 }))
+        );
+    }
+
+    #[test]
+    fn test_cache() {
+        assert_eq!(
+            parse_str("cache clear"),
+            Ok(Command::ClearCache(CacheArgs { cache_dir: None }))
+        );
+        assert_eq!(
+            parse_str("cache clear --cache-dir /tmp/aoc"),
+            Ok(Command::ClearCache(CacheArgs {
+                cache_dir: Some(PathBuf::from("/tmp/aoc"))
+            }))
+        );
+        assert!(parse_str("cache clear --day 1").is_err());
+        assert!(parse_str("cache").is_err());
+        assert!(parse_str("cache empty").is_err());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(parse_str("run").is_err());
+        assert!(parse_str("run --day 1 --all").is_err());
+        assert!(parse_str("run --all --input data/input.txt").is_err());
+        assert!(parse_str("run --day five").is_err());
+        assert!(parse_str("run --day 1 --part 3").is_err());
+        assert!(parse_str("run --day 1 --format yaml").is_err());
+        assert!(parse_str("run --all --jobs 0").is_err());
+        assert!(parse_str("run --all --trace day5=loud").is_err());
+        assert!(parse_str("run --all --explain --jobs 2").is_err());
+        assert!(parse_str("run --day 3 --explain --format json").is_err());
+        assert!(parse_str("walk --day 1").is_err());
+        assert!(parse_str("verify --part 1").is_err());
+        assert!(parse_str("batch --day 3").is_err());
+        assert!(parse_str("batch --dir inputs --format json").is_err());
+        assert!(parse_str("bench --runs 0").is_err());
+        assert!(parse_str("new").is_err());
+        assert!(parse_str("generate --day 8").is_err());
+        assert!(parse_str("bench --day 8 --size 10 --input data/input.txt").is_err());
+        assert!(parse_str("watch --day 3 --part 1").is_err());
+        assert!(parse_str("bench --input data/input.txt").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+//! Runs days against every input found in a directory, such as the inputs of
+//! several accounts.
+
+use crate::{days::Day, isolate::isolate};
+use aoc_common::{answers::ANSWERS_FILE, puzzle::parse_solution_file, table::format_table, Part};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// The result of running a day against one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    /// The input's file name.
+    pub input: String,
+    /// The answers of both parts, or why the input couldn't be parsed or a
+    /// part couldn't be solved.
+    pub answers: Result<[String; 2], String>,
+    /// How long parsing and solving both parts took.
+    pub elapsed: Duration,
+}
+
+/// The inputs of `dir`, sorted by name. Subdirectories and answer registries
+/// are skipped.
+pub fn inputs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut inputs = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.file_name().is_some_and(|name| name != ANSWERS_FILE) {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Run `day` against every input of `dir`. Inputs that can't be parsed or
+/// solved, even by panicking, get a row of their own instead of stopping the
+/// batch.
+pub fn run_batch(day: &Day, dir: &Path) -> io::Result<Vec<Row>> {
+    Ok(inputs(dir)?
+        .iter()
+        .map(|input| run_input(day, input))
+        .collect())
+}
+
+/// Run both parts of `day` against `input`.
+pub fn run_input(day: &Day, input: &Path) -> Row {
+    let start = Instant::now();
+    // Isolating the parts on their own tells which one panicked
+    let answers = isolate(|| parse_solution_file(day.parse, input))
+        .and_then(|solution| solution.map_err(|error| error.to_string()))
+        .and_then(|solution| {
+            let solve = |part| {
+                isolate(|| solution.solve(part))
+                    .and_then(|answer| answer.map_err(|error| error.to_string()))
+            };
+            match Part::ALL.map(solve) {
+                [Ok(part1), Ok(part2)] => Ok([part1, part2]),
+                [Err(error), _] => Err(format!("part 1: {error}")),
+                [_, Err(error)] => Err(format!("part 2: {error}")),
+            }
+        });
+    Row {
+        day: day.number,
+        input: input
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned(),
+        answers,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// The header and cells of `rows`, ready to be laid out.
+fn cells(rows: &[Row]) -> Vec<Vec<String>> {
+    let mut cells = vec![vec![
+        "Day".to_string(),
+        "Input".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+        "Time (ms)".to_string(),
+        "Error".to_string(),
+    ]];
+    for row in rows {
+        let (part1, part2, error) = match &row.answers {
+            Ok([part1, part2]) => (part1.clone(), part2.clone(), String::new()),
+            Err(error) => (String::new(), String::new(), error.clone()),
+        };
+        cells.push(vec![
+            row.day.to_string(),
+            row.input.clone(),
+            part1,
+            part2,
+            format!("{:.3}", row.elapsed.as_secs_f64() * 1e3),
+            error,
+        ]);
+    }
+    cells
+}
+
+pub fn format_rows(rows: &[Row]) -> String {
+    format_table(&cells(rows))
+}
+
+pub fn format_csv(rows: &[Row]) -> String {
+    let mut csv = String::new();
+    for row in cells(rows) {
+        let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// `field`, quoted if it holds anything that would break the CSV apart.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::PANICKING;
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                day: 9,
+                input: "alice.txt".to_string(),
+                answers: Ok(["114".to_string(), "2".to_string()]),
+                elapsed: Duration::from_micros(1500),
+            },
+            Row {
+                day: 9,
+                input: "bob.txt".to_string(),
+                answers: Err("bob.txt:1:3: invalid number `x`, oops".to_string()),
+                elapsed: Duration::from_micros(20),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format_rows() {
+        assert_eq!(
+            format_rows(&rows()),
+            "\
+Day  Input      Part 1  Part 2  Time (ms)  Error
+9    alice.txt  114     2       1.500
+9    bob.txt                    0.020      bob.txt:1:3: invalid number `x`, oops
+"
+        );
+    }
+
+    #[test]
+    fn test_run_input() {
+        let day = Day::find(9).unwrap();
+        let input = Path::new(day.data_dir).join("example.txt");
+        let row = run_input(day, &input);
+        assert_eq!(row.answers, Ok(["114".to_string(), "2".to_string()]));
+        let row = run_input(&PANICKING, &input);
+        assert_eq!(row.input, "example.txt");
+        assert_eq!(row.answers, Err("panicked: the parser gave up".to_string()));
+    }
+
+    #[test]
+    fn test_format_csv() {
+        assert_eq!(
+            format_csv(&rows()),
+            "\
+Day,Input,Part 1,Part 2,Time (ms),Error
+9,alice.txt,114,2,1.500,
+9,bob.txt,,,0.020,\"bob.txt:1:3: invalid number `x`, oops\"
+"
+        );
+    }
+}
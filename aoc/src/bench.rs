@@ -0,0 +1,278 @@
+//! Times the parsing and both parts of the days, and keeps baselines to compare
+//! later runs against.
+
+use crate::days::Day;
+use aoc_common::{ParseError, Part};
+use std::{
+    fmt, fs,
+    hint::black_box,
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Where baselines are saved, one file per baseline name.
+const BASELINE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../target/aoc-bench");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [
+        Stage::Parse,
+        Stage::Solve(Part::One),
+        Stage::Solve(Part::Two),
+    ];
+
+    fn from_name(name: &str) -> Option<Stage> {
+        Stage::ALL
+            .into_iter()
+            .find(|stage| stage.to_string() == name)
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+/// The spread of the times a stage took over several runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// The stats of `times`, which can't be empty.
+    pub fn from_times(mut times: Vec<Duration>) -> Self {
+        times.sort_unstable();
+        let middle = times.len() / 2;
+        let median = if times.len().is_multiple_of(2) {
+            (times[middle - 1] + times[middle]) / 2
+        } else {
+            times[middle]
+        };
+        Stats {
+            median,
+            min: times[0],
+            max: times[times.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Time every stage of `day` against the puzzle input `text`, `runs` times
+/// each.
+pub fn bench_day(day: &Day, text: &str, runs: usize) -> Result<Vec<Measurement>, ParseError> {
+    let parse = day.parse;
+    // Parse once outside of the timings so errors are reported right away
+    let solution = parse(text)?;
+    let mut measurements = vec![];
+    for stage in Stage::ALL {
+        let mut times = vec![];
+        for _ in 0..runs {
+            let start = Instant::now();
+            match stage {
+                Stage::Parse => {
+                    black_box(parse(text)?);
+                }
+                Stage::Solve(part) => {
+                    // A part without an answer takes its time all the same
+                    let _ = black_box(solution.solve(part));
+                }
+            }
+            times.push(start.elapsed());
+        }
+        measurements.push(Measurement {
+            day: day.number,
+            stage,
+            stats: Stats::from_times(times),
+        });
+    }
+    Ok(measurements)
+}
+
+fn baseline_path(name: &str) -> PathBuf {
+    Path::new(BASELINE_DIR).join(format!("{name}.txt"))
+}
+
+/// Lay out `measurements` as a baseline: one `<day> <stage> <median ns>` line
+/// each.
+fn format_baseline(measurements: &[Measurement]) -> String {
+    measurements
+        .iter()
+        .map(|m| format!("{} {} {}\n", m.day, m.stage, m.stats.median.as_nanos()))
+        .collect()
+}
+
+/// The median of every day and stage found in a baseline. Lines that can't be
+/// read are skipped, as the baseline may come from an older version.
+fn parse_baseline(text: &str) -> Vec<(u8, Stage, Duration)> {
+    text.lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let day = fields.next()?.parse::<u8>().ok()?;
+            let stage = Stage::from_name(fields.next()?)?;
+            let nanos = fields.next()?.parse::<u64>().ok()?;
+            Some((day, stage, Duration::from_nanos(nanos)))
+        })
+        .collect()
+}
+
+/// Save `measurements` as the baseline called `name`, replacing the days it
+/// already had measurements for.
+pub fn save_baseline(name: &str, measurements: &[Measurement]) -> io::Result<PathBuf> {
+    let path = baseline_path(name);
+    let mut kept: Vec<Measurement> = load_baseline(name)?
+        .into_iter()
+        .filter(|(day, _, _)| measurements.iter().all(|m| m.day != *day))
+        .map(|(day, stage, median)| Measurement {
+            day,
+            stage,
+            stats: Stats {
+                median,
+                min: median,
+                max: median,
+            },
+        })
+        .collect();
+    kept.extend_from_slice(measurements);
+    kept.sort_by_key(|m| (m.day, Stage::ALL.iter().position(|s| *s == m.stage)));
+    fs::create_dir_all(BASELINE_DIR)?;
+    fs::write(&path, format_baseline(&kept))?;
+    Ok(path)
+}
+
+/// The baseline called `name`, which is empty if it was never saved.
+pub fn load_baseline(name: &str) -> io::Result<Vec<(u8, Stage, Duration)>> {
+    match fs::read_to_string(baseline_path(name)) {
+        Ok(text) => Ok(parse_baseline(&text)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+/// How much slower (positive) or faster (negative) `median` is than `base`.
+fn format_change(median: Duration, base: Duration) -> String {
+    let change = (median.as_secs_f64() / base.as_secs_f64() - 1.0) * 100.0;
+    format!("{change:+.1}%")
+}
+
+/// Lay out `measurements` as a table, comparing them to `baseline` if given.
+pub fn format_measurements(
+    measurements: &[Measurement],
+    baseline: Option<&[(u8, Stage, Duration)]>,
+) -> String {
+    let mut table = format!(
+        "{:<4} {:<6} {:>10} {:>10} {:>10}",
+        "Day", "Stage", "Median", "Min", "Max"
+    );
+    if baseline.is_some() {
+        table.push_str(&format!(" {:>10}", "Change"));
+    }
+    table.push('\n');
+    for m in measurements {
+        table.push_str(&format!(
+            "{:<4} {:<6} {:>10} {:>10} {:>10}",
+            m.day,
+            m.stage.to_string(),
+            format_duration(m.stats.median),
+            format_duration(m.stats.min),
+            format_duration(m.stats.max)
+        ));
+        if let Some(baseline) = baseline {
+            let change = baseline
+                .iter()
+                .find(|(day, stage, _)| *day == m.day && *stage == m.stage)
+                .map(|(_, _, base)| format_change(m.stats.median, *base))
+                .unwrap_or_else(|| "-".to_string());
+            table.push_str(&format!(" {change:>10}"));
+        }
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let millis = |ms: &[u64]| ms.iter().map(|ms| Duration::from_millis(*ms)).collect();
+        assert_eq!(
+            Stats::from_times(millis(&[5, 1, 3])),
+            Stats {
+                median: Duration::from_millis(3),
+                min: Duration::from_millis(1),
+                max: Duration::from_millis(5),
+            }
+        );
+        assert_eq!(
+            Stats::from_times(millis(&[4, 1, 2, 9])).median,
+            Duration::from_millis(3)
+        );
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let stats = Stats {
+            median: Duration::from_micros(1500),
+            min: Duration::from_micros(1000),
+            max: Duration::from_micros(2000),
+        };
+        let measurements = vec![
+            Measurement {
+                day: 5,
+                stage: Stage::Parse,
+                stats,
+            },
+            Measurement {
+                day: 5,
+                stage: Stage::Solve(Part::Two),
+                stats,
+            },
+        ];
+        let baseline = format_baseline(&measurements);
+        assert_eq!(baseline, "5 parse 1500000\n5 part2 1500000\n");
+        assert_eq!(
+            parse_baseline(&baseline),
+            vec![
+                (5, Stage::Parse, Duration::from_micros(1500)),
+                (5, Stage::Solve(Part::Two), Duration::from_micros(1500))
+            ]
+        );
+        assert_eq!(
+            format_change(Duration::from_millis(3), Duration::from_millis(2)),
+            "+50.0%"
+        );
+    }
+}
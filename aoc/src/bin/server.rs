@@ -0,0 +1,42 @@
+//! Serves the day solvers as a JSON API on localhost, see [`aoc::server`].
+
+use aoc::server::{self, DEFAULT_PORT};
+use std::{env, process::ExitCode};
+
+const USAGE: &str = "\
+Usage:
+    aoc-server [--port <PORT>]
+
+Answers `POST /day/<N>/part/<1|2>` requests, whose body is the puzzle input,
+on 127.0.0.1:<PORT> (2023 by default).";
+
+fn parse_port(mut args: impl Iterator<Item = String>) -> Result<u16, String> {
+    match (args.next().as_deref(), args.next(), args.next()) {
+        (None, _, _) => Ok(DEFAULT_PORT),
+        (Some("--port"), Some(port), None) => port
+            .parse()
+            .map_err(|_| format!("`--port` expects a port, got `{port}`")),
+        (Some(arg), _, _) => Err(format!("unexpected argument `{arg}`")),
+    }
+}
+
+fn main() -> ExitCode {
+    let port = match parse_port(env::args().skip(1)) {
+        Ok(port) => port,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let served = server::bind(port).and_then(|listener| {
+        println!("Listening on http://{}", listener.local_addr()?);
+        server::serve(listener)
+    });
+    match served {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: unable to serve on port {port}: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
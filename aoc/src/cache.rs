@@ -0,0 +1,217 @@
+//! The on-disk cache of answers, so that slow parts are only solved again
+//! when their input or their solver changes.
+
+use aoc_common::{
+    numeric::{self, Integer},
+    Part,
+};
+use std::{
+    env,
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Where answers are cached unless told otherwise: `aoc` in the user's cache
+/// directory, `$XDG_CACHE_HOME` or else `~/.cache`.
+pub fn default_dir() -> PathBuf {
+    cache_dir(env::var_os("XDG_CACHE_HOME"), env::var_os("HOME"))
+}
+
+fn cache_dir(xdg_cache_home: Option<OsString>, home: Option<OsString>) -> PathBuf {
+    // Relative paths are to be ignored, like unset ones
+    let absolute = |dir: Option<OsString>| dir.map(PathBuf::from).filter(|dir| dir.is_absolute());
+    let base = absolute(xdg_cache_home)
+        .or_else(|| absolute(home).map(|home| home.join(".cache")))
+        .unwrap_or_else(env::temp_dir);
+    base.join("aoc")
+}
+
+/// What an answer depends on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Key {
+    pub day: u8,
+    pub part: Part,
+    /// The [`Puzzle::VERSION`](aoc_common::Puzzle::VERSION) of the day.
+    pub version: u32,
+    /// The type answers are accumulated in, such as `i64`, which the `wide`
+    /// feature widens so that answers that overflowed can change.
+    pub width: &'static str,
+    /// The FNV-1a hash of the input, which is the same on every platform and
+    /// Rust release.
+    pub input_hash: u64,
+    /// The length of the input in bytes, so that a hash collision would also
+    /// need inputs of the same length.
+    pub input_len: usize,
+}
+
+impl Key {
+    pub fn new(day: u8, part: Part, version: u32, input: &str) -> Self {
+        Key {
+            day,
+            part,
+            version,
+            width: numeric::Int::NAME,
+            input_hash: fnv1a(input.as_bytes()),
+            input_len: input.len(),
+        }
+    }
+
+    fn filename(&self) -> String {
+        format!(
+            "day{}-part{}-v{}-{}-{}-{:016x}.txt",
+            self.day, self.part, self.version, self.width, self.input_len, self.input_hash
+        )
+    }
+}
+
+/// The 64-bit FNV-1a hash of `bytes`. Unlike the standard library's hashers,
+/// it's specified, so cached answers survive new Rust releases.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Whether `filename` is one [`Key::filename`] could have written, so that
+/// clearing the cache leaves any other file in its directory alone.
+fn is_answer_file(filename: &str) -> bool {
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let Some(stem) = filename.strip_suffix(".txt") else {
+        return false;
+    };
+    let fields: Vec<&str> = stem.split('-').collect();
+    let [day, part, version, width, len, hash] = fields[..] else {
+        return false;
+    };
+    day.strip_prefix("day").is_some_and(digits)
+        && part.strip_prefix("part").is_some_and(digits)
+        && version.strip_prefix('v').is_some_and(digits)
+        && !width.is_empty()
+        && width.bytes().all(|b| b.is_ascii_alphanumeric())
+        && digits(len)
+        && hash.len() == 16
+        && hash.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// A directory holding one file per cached answer.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new<P>(dir: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        Cache {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// The answer cached for `key`, if any.
+    pub fn get(&self, key: &Key) -> Option<String> {
+        fs::read_to_string(self.dir.join(key.filename())).ok()
+    }
+
+    pub fn put(&self, key: &Key, answer: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.dir.join(key.filename()), answer)
+    }
+
+    /// Forget every cached answer, returning how many there were. Files that
+    /// aren't cached answers are kept.
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(error) => return Err(error),
+        };
+        let mut count = 0;
+        for entry in entries {
+            let entry = entry?;
+            let is_answer = entry.file_name().to_str().is_some_and(is_answer_file);
+            if is_answer && entry.file_type()?.is_file() {
+                fs::remove_file(entry.path())?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        let key = Key::new(5, Part::Two, 1, "seeds: 79 14 55 13\n");
+        assert_eq!(cache.get(&key), None);
+        cache.put(&key, "46").unwrap();
+        assert_eq!(cache.get(&key).as_deref(), Some("46"));
+        // Any change to the input, the solver or its width misses the cache
+        assert_eq!(
+            cache.get(&Key::new(5, Part::Two, 1, "seeds: 79 14\n")),
+            None
+        );
+        assert_eq!(
+            cache.get(&Key::new(5, Part::Two, 2, "seeds: 79 14 55 13\n")),
+            None
+        );
+        assert_eq!(
+            cache.get(&Key::new(5, Part::One, 1, "seeds: 79 14 55 13\n")),
+            None
+        );
+        assert_eq!(
+            cache.get(&Key {
+                width: "i32",
+                ..key.clone()
+            }),
+            None
+        );
+        // Clearing only removes answers, even from a directory shared with
+        // other files
+        fs::write(dir.join("notes.txt"), "keep").unwrap();
+        assert_eq!(cache.clear().unwrap(), 1);
+        assert_eq!(cache.get(&key), None);
+        assert!(dir.join("notes.txt").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_key() {
+        let key = Key::new(8, Part::One, 1, "a");
+        // The published FNV-1a test vectors, which no Rust release changes
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(key.input_hash, 0xaf63dc4c8601ec8c);
+        assert!(is_answer_file(&key.filename()));
+        assert!(is_answer_file("day25-part1-v3-i128-0-00000000deadbeef.txt"));
+        assert!(!is_answer_file("notes.txt"));
+        assert!(!is_answer_file(
+            "day8-part1-v1-i64-1-af63dc4c8601ec8c.txt.bak"
+        ));
+        assert!(!is_answer_file("day8-part1-v1-i64-1-af63dc4c.txt"));
+        assert!(!is_answer_file("day8-part1-i64-1-af63dc4c8601ec8c.txt"));
+    }
+
+    #[test]
+    fn test_cache_dir() {
+        let dir = |xdg: Option<&str>, home: Option<&str>| {
+            cache_dir(xdg.map(OsString::from), home.map(OsString::from))
+        };
+        assert_eq!(
+            dir(Some("/var/cache"), Some("/home/elf")),
+            Path::new("/var/cache/aoc")
+        );
+        assert_eq!(
+            dir(Some("cache"), Some("/home/elf")),
+            Path::new("/home/elf/.cache/aoc")
+        );
+        assert_eq!(dir(None, None), env::temp_dir().join("aoc"));
+    }
+}
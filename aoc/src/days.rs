@@ -0,0 +1,78 @@
+use aoc_common::{
+    generate::Generate,
+    puzzle::{parse_solution, Parser},
+    query::Query,
+    Puzzle,
+};
+use std::path::{Path, PathBuf};
+
+pub struct Day {
+    pub number: u8,
+    pub data_dir: &'static str,
+    pub parse: Parser,
+    /// The day's [`Puzzle::VERSION`].
+    pub version: u32,
+    /// The day's [`Puzzle::QUERIES`].
+    pub queries: &'static [Query],
+    /// Writes synthetic puzzle input of a size, from a seed.
+    pub generate: fn(usize, u64) -> String,
+}
+
+impl Day {
+    const fn new<P>(number: u8, data_dir: &'static str) -> Day
+    where
+        P: Puzzle + Generate + 'static,
+    {
+        Day {
+            number,
+            data_dir,
+            parse: parse_solution::<P>,
+            version: P::VERSION,
+            queries: P::QUERIES,
+            generate: P::generate,
+        }
+    }
+
+    pub fn find(number: u8) -> Option<&'static Day> {
+        DAYS.iter().find(|day| day.number == number)
+    }
+
+    /// The day with the given number, or all days if there's no number.
+    pub fn select(number: Option<u8>) -> Result<Vec<&'static Day>, String> {
+        match number {
+            Some(number) => match Day::find(number) {
+                Some(day) => Ok(vec![day]),
+                None => Err(format!("day {number} isn't solved yet")),
+            },
+            None => Ok(DAYS.iter().collect()),
+        }
+    }
+
+    pub fn default_input(&self) -> PathBuf {
+        Path::new(self.data_dir).join("input.txt")
+    }
+}
+
+/// A day whose parser panics, to check that the panic stays with the input
+/// it happened on.
+#[cfg(test)]
+pub(crate) const PANICKING: Day = Day {
+    number: 0,
+    data_dir: "",
+    parse: |_| panic!("the parser gave up"),
+    version: 0,
+    queries: &[],
+    generate: |_, _| String::new(),
+};
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::CalibrationDocument>(1, day1::DATA_DIR),
+    Day::new::<day2::GameRecord>(2, day2::DATA_DIR),
+    Day::new::<day3::Engine>(3, day3::DATA_DIR),
+    Day::new::<day4::CardPile>(4, day4::DATA_DIR),
+    Day::new::<day5::Almanac>(5, day5::DATA_DIR),
+    Day::new::<day6::RaceSheet>(6, day6::DATA_DIR),
+    Day::new::<day7::CamelCards>(7, day7::DATA_DIR),
+    Day::new::<day8::Network>(8, day8::DATA_DIR),
+    Day::new::<day9::OasisReport>(9, day9::DATA_DIR),
+];
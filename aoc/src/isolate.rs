@@ -0,0 +1,47 @@
+//! Keeps a solver that panics from taking everything else down with it, such
+//! as the other inputs of a batch.
+
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+};
+
+/// Run `f`, turning a panic into an error holding its message.
+pub fn isolate<T, F>(f: F) -> Result<T, String>
+where
+    F: FnOnce() -> T,
+{
+    // Nothing `f` touched is looked at again after it panics
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map_err(|payload| format!("panicked: {}", message(payload.as_ref())))
+}
+
+/// The message a panic was raised with.
+pub fn message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown error"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isolate() {
+        assert_eq!(isolate(|| 42), Ok(42));
+        assert_eq!(
+            isolate(|| -> u8 { panic!("no answer") }),
+            Err("panicked: no answer".to_string())
+        );
+        let day = 9;
+        assert_eq!(
+            isolate(|| -> u8 { panic!("day {day} has no answer") }),
+            Err("panicked: day 9 has no answer".to_string())
+        );
+    }
+}
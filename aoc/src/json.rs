@@ -0,0 +1,136 @@
+//! Just enough JSON to print results for scripts and dashboards to consume.
+
+use std::fmt::{self, Write};
+
+/// `text` as a JSON string, quotes included.
+pub fn string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Whether `text` is an integer the way JSON writes them, without a plus
+/// sign or leading zeros.
+fn is_integer(text: &str) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    match digits.as_bytes() {
+        [] => false,
+        [b'0', _, ..] => false,
+        digits => digits.iter().all(u8::is_ascii_digit),
+    }
+}
+
+/// A JSON object whose fields keep the order they were added in.
+#[derive(Debug, Default)]
+pub struct Object {
+    fields: Vec<(String, String)>,
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Object::default()
+    }
+
+    pub fn string(mut self, key: &str, value: &str) -> Self {
+        self.fields.push((key.to_string(), string(value)));
+        self
+    }
+
+    pub fn boolean(mut self, key: &str, value: bool) -> Self {
+        self.fields.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Add `value` as a number if it's an integer, and as a string otherwise.
+    pub fn number_or_string(self, key: &str, value: &str) -> Self {
+        if is_integer(value) {
+            self.number(key, value)
+        } else {
+            self.string(key, value)
+        }
+    }
+
+    /// Add `value`, which must display as a valid JSON number.
+    pub fn number<T>(mut self, key: &str, value: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        self.fields.push((key.to_string(), value.to_string()));
+        self
+    }
+}
+
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", string(key), value)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// `items` as a JSON array, one item per line.
+pub fn array<T>(items: &[T]) -> String
+where
+    T: fmt::Display,
+{
+    if items.is_empty() {
+        return "[]".to_string();
+    }
+    let items: Vec<String> = items.iter().map(|item| format!("  {item}")).collect();
+    format!("[\n{}\n]", items.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("day5/data/input.txt"), r#""day5/data/input.txt""#);
+        assert_eq!(string("a \"b\"\\\n\u{1}"), r#""a \"b\"\\\n\u0001""#);
+    }
+
+    #[test]
+    fn test_number_or_string() {
+        let object = Object::new()
+            .number_or_string("a", "-3")
+            .number_or_string("b", "0")
+            .number_or_string("c", "007")
+            .number_or_string("d", "1,2")
+            .number_or_string("e", "-");
+        assert_eq!(
+            object.to_string(),
+            r#"{"a": -3, "b": 0, "c": "007", "d": "1,2", "e": "-"}"#
+        );
+    }
+
+    #[test]
+    fn test_array() {
+        let objects = [
+            Object::new().number("day", 9).string("answer", "-3"),
+            Object::new().number("day", 5).number("elapsed_ms", 0.25),
+        ];
+        assert_eq!(
+            array(&objects),
+            "[\n  {\"day\": 9, \"answer\": \"-3\"},\n  {\"day\": 5, \"elapsed_ms\": 0.25}\n]"
+        );
+        assert_eq!(array::<Object>(&[]), "[]");
+    }
+}
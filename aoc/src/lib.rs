@@ -0,0 +1,18 @@
+//! The runner of the days, shared by the `aoc` command and the server.
+
+pub mod args;
+pub mod batch;
+pub mod bench;
+pub mod cache;
+pub mod days;
+pub mod isolate;
+pub mod json;
+pub mod pool;
+pub mod repl;
+pub mod report;
+pub mod scaffold;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod summary;
+pub mod trace;
+pub mod watch;
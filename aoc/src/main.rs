@@ -0,0 +1,454 @@
+//! Runs any part of any day against any input.
+
+use aoc::{
+    args::{
+        self, BatchArgs, BatchFormat, BenchArgs, CacheArgs, Command, Format, GenerateArgs, NewArgs,
+        ReplArgs, ReportArgs, RunArgs, VerifyArgs, WatchArgs, USAGE,
+    },
+    batch, bench,
+    cache::{self, Cache},
+    days::{Day, DAYS},
+    json::{self, Object},
+    pool,
+    repl::{self, Session},
+    report::{self, DayReport, Verdict, REPORT_DIR},
+    scaffold,
+    summary::{self, DayRun},
+    trace, watch,
+};
+use aoc_common::{answers, input::load_input, puzzle::parse_solution_file, SolveError};
+use std::{env, fs, io, path::Path, process::ExitCode, time::Instant};
+
+fn main() -> ExitCode {
+    match args::parse(env::args().skip(1)) {
+        Ok(Command::Run(args)) => run(args),
+        Ok(Command::Verify(args)) => verify(args),
+        Ok(Command::Batch(args)) => batch(args),
+        Ok(Command::Bench(args)) => bench(args),
+        Ok(Command::Generate(args)) => generate(args),
+        Ok(Command::Watch(args)) => watch(args),
+        Ok(Command::Repl(args)) => repl(args),
+        Ok(Command::Report(args)) => report(args),
+        Ok(Command::New(args)) => new_day(args),
+        Ok(Command::ClearCache(args)) => clear_cache(args),
+        Ok(Command::Help) => {
+            println!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let days = match Day::select(args.day) {
+        Ok(days) => days,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(filter) = &args.trace {
+        // The filter was checked when reading the arguments
+        trace::init(trace::parse_filter(filter).unwrap());
+    }
+    if args.explain {
+        return explain(&days, &args);
+    }
+    let cache = Cache::new(args.cache_dir.clone().unwrap_or_else(cache::default_dir));
+    // Cached answers aren't solved, so there would be nothing to trace or check
+    let cache = (args.use_cache && args.trace.is_none() && !args.checked).then_some(&cache);
+    let input = |day: &Day| args.input.clone().unwrap_or_else(|| day.default_input());
+    let run_day = |day: &&Day| summary::run_day(day, input(day), &args.parts, cache, args.checked);
+    let runs = match args.jobs {
+        Some(jobs) => {
+            let start = Instant::now();
+            let runs: Vec<DayRun> = pool::map(&days, jobs, run_day)
+                .into_iter()
+                .zip(&days)
+                .map(|(run, day)| {
+                    run.unwrap_or_else(|message| DayRun::panicked(day, input(day), message))
+                })
+                .collect();
+            if args.format == Format::Text {
+                print!("{}", summary::format_summary(&runs, start.elapsed(), jobs));
+            }
+            runs
+        }
+        None => days
+            .iter()
+            .map(|day| {
+                let run = run_day(day);
+                if args.format == Format::Text {
+                    print_run(&run);
+                }
+                run
+            })
+            .collect(),
+    };
+    if args.format == Format::Json {
+        let results: Vec<Object> = runs.iter().flat_map(json_results).collect();
+        println!("{}", json::array(&results));
+    }
+    let solved = runs.iter().all(|run| {
+        run.parts
+            .as_ref()
+            .is_ok_and(|parts| parts.iter().all(|part| part.answer.is_ok()))
+    });
+    if solved {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn explain(days: &[&Day], args: &RunArgs) -> ExitCode {
+    let mut failed = false;
+    for day in days {
+        let input = args.input.clone().unwrap_or_else(|| day.default_input());
+        let solution = match parse_solution_file(day.parse, &input) {
+            Ok(solution) => solution,
+            Err(error) => {
+                eprintln!("Day {}: error: {}", day.number, error);
+                failed = true;
+                continue;
+            }
+        };
+        for part in &args.parts {
+            match solution.explain(*part) {
+                Ok(Some(explanation)) => {
+                    println!("Day {} part {}:\n{}", day.number, part, explanation)
+                }
+                Ok(None) => {
+                    eprintln!("Day {} part {}: can't be explained", day.number, part);
+                    failed = true;
+                }
+                Err(SolveError::Overflow(overflow)) if args.checked => {
+                    eprintln!("Day {} part {}: overflow at {}", day.number, part, overflow);
+                    failed = true;
+                }
+                Err(error) => {
+                    eprintln!("Day {} part {}: error: {}", day.number, part, error);
+                    failed = true;
+                }
+            }
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_run(run: &DayRun) {
+    match &run.parts {
+        Ok(parts) => {
+            for part in parts {
+                match (&part.overflow, &part.answer) {
+                    (Some(overflow), _) => {
+                        eprintln!(
+                            "Day {} part {}: overflow at {}",
+                            run.day, part.part, overflow
+                        )
+                    }
+                    (None, Ok(answer)) => {
+                        println!("Day {} part {}: {}", run.day, part.part, answer)
+                    }
+                    (None, Err(error)) => {
+                        eprintln!("Day {} part {}: error: {}", run.day, part.part, error)
+                    }
+                }
+            }
+        }
+        Err(error) => eprintln!("Day {}: error: {}", run.day, error),
+    }
+}
+
+fn json_results(run: &DayRun) -> Vec<Object> {
+    let input = run.input.display().to_string();
+    match &run.parts {
+        Ok(parts) => parts
+            .iter()
+            .map(|part| {
+                let result = Object::new()
+                    .number("day", run.day)
+                    .number("part", part.part)
+                    .string("input", &input);
+                let result = match (&part.overflow, &part.answer) {
+                    (Some(overflow), _) => result.string("overflow", &overflow.to_string()),
+                    (None, Ok(answer)) => result.number_or_string("answer", answer),
+                    (None, Err(error)) => result.string("error", &error.to_string()),
+                };
+                result
+                    .number("elapsed_ms", part.elapsed.as_secs_f64() * 1e3)
+                    .boolean("cached", part.cached)
+            })
+            .collect(),
+        Err(error) => vec![Object::new()
+            .number("day", run.day)
+            .string("input", &input)
+            .string("error", &error.to_string())],
+    }
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let days = match Day::select(args.day) {
+        Ok(days) => days,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut passed = 0;
+    let mut total = 0;
+    for day in days {
+        println!("Day {}", day.number);
+        match answers::verify(Path::new(day.data_dir), day.parse) {
+            Ok(checks) => {
+                println!("{}", answers::format_checks(&checks));
+                passed += checks.iter().filter(|check| check.passed()).count();
+                total += checks.len();
+            }
+            Err(error) => {
+                println!("error: {error}\n");
+                // A registry that can't be read counts as a failed check
+                total += 1;
+            }
+        }
+    }
+    println!("{passed}/{total} answers verified");
+    if passed == total {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn batch(args: BatchArgs) -> ExitCode {
+    let days = match Day::select(args.day) {
+        Ok(days) => days,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut rows = vec![];
+    for day in days {
+        let dir = match args.day {
+            Some(_) => args.dir.clone(),
+            None => args.dir.join(format!("day{}", day.number)),
+        };
+        if args.day.is_none() && !dir.is_dir() {
+            continue;
+        }
+        match batch::run_batch(day, &dir) {
+            Ok(day_rows) => rows.extend(day_rows),
+            Err(error) => {
+                eprintln!("error: unable to read {}: {}", dir.display(), error);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    match args.format {
+        BatchFormat::Table => print!("{}", batch::format_rows(&rows)),
+        BatchFormat::Csv => print!("{}", batch::format_csv(&rows)),
+    }
+    if rows.iter().all(|row| row.answers.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let days = match Day::select(args.day) {
+        Ok(days) => days,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let baseline = match args.baseline.as_deref().map(bench::load_baseline) {
+        Some(Ok(baseline)) => Some(baseline),
+        Some(Err(error)) => {
+            eprintln!("error: unable to read the baseline: {error}");
+            return ExitCode::FAILURE;
+        }
+        None => None,
+    };
+    let mut failed = false;
+    let mut measurements = vec![];
+    for day in days {
+        let day_measurements = match args.size {
+            Some(size) => bench::bench_day(day, &(day.generate)(size, args.seed), args.runs),
+            None => {
+                let input = args.input.clone().unwrap_or_else(|| day.default_input());
+                load_input(&input).and_then(|text| {
+                    bench::bench_day(day, &text, args.runs).map_err(|error| error.with_file(&input))
+                })
+            }
+        };
+        match day_measurements {
+            Ok(day_measurements) => measurements.extend(day_measurements),
+            Err(error) => {
+                eprintln!("Day {}: error: {}", day.number, error);
+                failed = true;
+            }
+        }
+    }
+    print!(
+        "{}",
+        bench::format_measurements(&measurements, baseline.as_deref())
+    );
+    if let Some(name) = &args.save {
+        match bench::save_baseline(name, &measurements) {
+            Ok(path) => println!("\nSaved baseline `{name}` to {}", path.display()),
+            Err(error) => {
+                eprintln!("error: unable to save the baseline: {error}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn generate(args: GenerateArgs) -> ExitCode {
+    match Day::select(Some(args.day)) {
+        Ok(days) => {
+            print!("{}", (days[0].generate)(args.size, args.seed));
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn watch(args: WatchArgs) -> ExitCode {
+    let day = match Day::select(Some(args.day)) {
+        Ok(days) => days[0],
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match watch::watch(day) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: unable to watch {}: {}", day.data_dir, error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn repl(args: ReplArgs) -> ExitCode {
+    let day = match Day::select(Some(args.day)) {
+        Ok(days) => days[0],
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = args.input.unwrap_or_else(|| day.default_input());
+    let mut session = match Session::new(day, input) {
+        Ok(session) => session,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!(
+        "Loaded {}, type `help` for the commands",
+        session.input().display()
+    );
+    match repl::repl(&mut session, io::stdin().lock(), io::stdout()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn report(args: ReportArgs) -> ExitCode {
+    let days: Vec<&Day> = DAYS.iter().collect();
+    let start = Instant::now();
+    let reports: Vec<DayReport> = match args.jobs {
+        Some(jobs) => pool::map(&days, jobs, |day| report::report_day(day))
+            .into_iter()
+            .zip(&days)
+            .map(|(report, day)| report.unwrap_or_else(|message| DayReport::panicked(day, message)))
+            .collect(),
+        None => days.iter().map(|day| report::report_day(day)).collect(),
+    };
+    let elapsed = start.elapsed();
+    let out = args.out.unwrap_or_else(|| REPORT_DIR.into());
+    let written = fs::create_dir_all(&out).and_then(|()| {
+        let markdown = out.join("report.md");
+        fs::write(&markdown, report::format_markdown(&reports, elapsed))?;
+        let html = out.join("report.html");
+        fs::write(&html, report::format_html(&reports, elapsed))?;
+        Ok::<_, io::Error>([markdown, html])
+    });
+    match written {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Err(error) => {
+            eprintln!("error: unable to write the reports: {error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    let failed = reports
+        .iter()
+        .flat_map(DayReport::verdicts)
+        .any(|verdict| matches!(verdict, Verdict::Fail | Verdict::Error));
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn new_day(args: NewArgs) -> ExitCode {
+    if Day::find(args.day).is_some() {
+        eprintln!("error: day {} is already solved", args.day);
+        return ExitCode::FAILURE;
+    }
+    match scaffold::new_day(Path::new(scaffold::WORKSPACE_DIR), args.day) {
+        Ok(()) => {
+            println!(
+                "Created day{0}. Add its inputs and answers to day{0}/data, then remove the `#[ignore]` of its tests and run `cargo test -p day{0}`.",
+                args.day
+            );
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn clear_cache(args: CacheArgs) -> ExitCode {
+    match Cache::new(args.cache_dir.unwrap_or_else(cache::default_dir)).clear() {
+        Ok(count) => {
+            println!("Removed {count} cached answers");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: unable to clear the cache: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
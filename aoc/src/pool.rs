@@ -0,0 +1,71 @@
+//! A minimal thread pool for running independent jobs, such as days.
+
+use crate::isolate::isolate;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// Apply `f` to every item on `jobs` threads, returning the results in the
+/// order of the items. An item that `f` panics on gets the panic's message
+/// as its result, without stopping the other items.
+pub fn map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<Result<R, String>>>> =
+        items.iter().map(|_| Mutex::new(None)).collect();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = isolate(|| f(item));
+                *results[index].lock().unwrap() = Some(result);
+            });
+        }
+    });
+    results
+        .into_iter()
+        .map(|result| result.into_inner().unwrap().expect("every item was run"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_map() {
+        let items: Vec<u64> = (0..20).collect();
+        let squares = map(&items, 4, |&n| {
+            // Finish the items out of order
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+        assert_eq!(squares, items.iter().map(|n| Ok(n * n)).collect::<Vec<_>>());
+        assert_eq!(map(&items[..0], 4, |&n| n), vec![]);
+        let halves = map(&items[..4], 2, |&n| {
+            assert!(n % 2 == 0, "{n} is odd");
+            n / 2
+        });
+        assert_eq!(
+            halves,
+            [
+                Ok(0),
+                Err("panicked: 1 is odd".to_string()),
+                Ok(1),
+                Err("panicked: 3 is odd".to_string())
+            ]
+        );
+    }
+}
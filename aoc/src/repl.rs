@@ -0,0 +1,196 @@
+//! A prompt that parses a day's input once and answers questions about it,
+//! such as `location 79`, to explore a puzzle without recompiling.
+
+use crate::{bench::format_duration, days::Day, isolate::isolate};
+use aoc_common::{
+    puzzle::{parse_solution_file, Solution},
+    query::{self, Reply},
+    table::format_table,
+    ParseError, Part,
+};
+use std::{
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+/// The commands every day answers, besides its own queries.
+const COMMANDS: [(&str, &str); 5] = [
+    ("part <1|2>", "solve a part"),
+    (
+        "explain <1|2>",
+        "what every item contributed to a part's answer",
+    ),
+    ("load <path>", "parse another input"),
+    ("help", "show this help"),
+    ("quit", "leave, as does the end of the input"),
+];
+
+/// A day's input, parsed.
+pub struct Session {
+    day: &'static Day,
+    input: PathBuf,
+    solution: Box<dyn Solution>,
+}
+
+impl Session {
+    /// Parse `input` for `day`.
+    pub fn new(day: &'static Day, input: PathBuf) -> Result<Self, ParseError> {
+        let solution = parse_solution_file(day.parse, &input)?;
+        Ok(Session {
+            day,
+            input,
+            solution,
+        })
+    }
+
+    fn help(&self) -> String {
+        let mut rows = vec![];
+        for query in self.day.queries {
+            rows.push(vec![query.usage.to_string(), query.help.to_string()]);
+        }
+        for (usage, help) in COMMANDS {
+            rows.push(vec![usage.to_string(), help.to_string()]);
+        }
+        format_table(&rows).trim_end().to_string()
+    }
+
+    fn part(text: &str) -> Result<Part, String> {
+        let number = query::number(text)?;
+        Part::from_number(number).ok_or_else(|| format!("part {number} doesn't exist"))
+    }
+
+    /// Answer the command made of `words`, which isn't empty nor `quit`.
+    fn respond(&mut self, words: &[&str]) -> Reply {
+        match words {
+            ["help"] => Ok(self.help()),
+            ["part", part] => {
+                let part = Self::part(part)?;
+                let start = Instant::now();
+                let answer = self.solution.solve(part).map_err(|e| e.to_string())?;
+                Ok(format!("{answer} ({})", format_duration(start.elapsed())))
+            }
+            ["explain", part] => {
+                let part = Self::part(part)?;
+                match self.solution.explain(part) {
+                    Ok(Some(explanation)) => Ok(explanation.to_string().trim_end().to_string()),
+                    Err(error) => Err(error.to_string()),
+                    Ok(None) => Err(format!("part {part} can't be explained")),
+                }
+            }
+            ["load", path] => {
+                // Keep the current input if the new one can't be parsed
+                *self = Session::new(self.day, PathBuf::from(path)).map_err(|e| e.to_string())?;
+                Ok(format!("Loaded {}", self.input.display()))
+            }
+            _ => self.solution.query(words).unwrap_or_else(|| {
+                Err(format!("unknown command `{}`, try `help`", words.join(" ")))
+            }),
+        }
+    }
+
+    pub fn input(&self) -> &Path {
+        &self.input
+    }
+}
+
+/// Read commands from `reader` and write their replies to `writer`, until
+/// `quit` or the end of the input.
+pub fn repl<R, W>(session: &mut Session, reader: R, mut writer: W) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut lines = reader.lines();
+    loop {
+        write!(writer, "day{}> ", session.day.number)?;
+        writer.flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            // Leave the shell's prompt on a line of its own
+            return writeln!(writer);
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words[..] {
+            [] => {}
+            ["quit"] | ["exit"] => return Ok(()),
+            // A command the day panics on leaves the session as it was
+            _ => match isolate(|| session.respond(&words)).unwrap_or_else(Err) {
+                Ok(reply) => writeln!(writer, "{reply}")?,
+                Err(error) => writeln!(writer, "error: {error}")?,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::PANICKING;
+
+    fn run(day: u8, input: &str, commands: &str) -> String {
+        let day = Day::find(day).unwrap();
+        let mut session = Session::new(day, Path::new(day.data_dir).join(input)).unwrap();
+        let mut output = vec![];
+        repl(&mut session, commands.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_repl() {
+        let output = run(
+            6,
+            "example.txt",
+            "race 1\n\nrace 4\nexplain 1\nfly\nquit\nrace 2\n",
+        );
+        assert_eq!(
+            output,
+            "\
+day6> 7ms to beat 9mm: hold 2 to 5ms, 4 ways
+day6> day6> error: there's no race 4, only 3 of them
+day6> Item    Detail                               Value
+Race 1  7ms to beat 9mm: hold 2 to 5ms       4
+Race 2  15ms to beat 40mm: hold 4 to 11ms    8
+Race 3  30ms to beat 200mm: hold 11 to 19ms  9
+Total (product): 288
+day6> error: unknown command `fly`, try `help`
+day6> "
+        );
+    }
+
+    #[test]
+    fn test_load_and_solve() {
+        let example = format!("{}/example2.txt", day8::DATA_DIR);
+        let output = run(
+            8,
+            "input.txt",
+            &format!("load missing.txt\nload {example}\npath AAA\npart 3\n"),
+        );
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].starts_with("day8> error: missing.txt: unable to read input"));
+        assert_eq!(lines[1], format!("day8> Loaded {example}"));
+        assert_eq!(lines[2], "day8> error: there's no node AAA");
+        assert_eq!(lines[3], "day8> error: part 3 doesn't exist");
+        assert_eq!(lines[4], "day8> ");
+    }
+
+    #[test]
+    fn test_panic() {
+        let example = format!("{}/example.txt", day6::DATA_DIR);
+        let mut session = Session::new(Day::find(6).unwrap(), example.clone().into()).unwrap();
+        session.day = &PANICKING;
+        let mut output = vec![];
+        let commands = format!("load {example}\npart 1\n");
+        repl(&mut session, commands.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "day0> error: panicked: the parser gave up");
+        assert!(lines[1].starts_with("day0> 288 ("), "{output}");
+    }
+
+    #[test]
+    fn test_help() {
+        let output = run(5, "example.txt", "help\n");
+        assert!(output.starts_with("day5> location <seed>  "), "{output}");
+        assert!(output.contains("\npart <1|2>  "), "{output}");
+    }
+}
@@ -0,0 +1,314 @@
+//! Reports of a run of every day, in Markdown and as a standalone HTML page,
+//! for pasting into a wiki.
+
+use crate::{
+    bench::format_duration,
+    days::Day,
+    summary::{self, DayRun},
+};
+use aoc_common::{
+    answers::{load_answers, Answer},
+    Part,
+};
+use std::{fs, path::Path, time::Duration};
+
+/// Where reports are written unless `--out` is given.
+pub const REPORT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../target/aoc-report");
+
+/// How a part's answer compares to the answer registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    /// No answer is registered for the part.
+    Unregistered,
+    /// The input, or the registry, couldn't be read, or the part has no
+    /// answer.
+    Error,
+}
+
+impl Verdict {
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "FAIL",
+            Verdict::Unregistered => "unregistered",
+            Verdict::Error => "ERROR",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub run: DayRun,
+    /// The lines and bytes of the input, if it could be read.
+    pub size: Option<(usize, usize)>,
+    /// The answers registered for the input, or why they couldn't be read.
+    pub expected: Result<Vec<Answer>, String>,
+}
+
+impl DayReport {
+    /// The report of `day` that panicked with `message` before it was done.
+    pub fn panicked(day: &Day, message: String) -> Self {
+        DayReport {
+            run: DayRun::panicked(day, day.default_input(), message),
+            size: None,
+            expected: Ok(vec![]),
+        }
+    }
+
+    fn expected(&self, part: Part) -> Option<&str> {
+        self.expected
+            .as_ref()
+            .ok()?
+            .iter()
+            .find(|answer| answer.part == part)
+            .map(|answer| answer.answer.as_str())
+    }
+
+    pub fn verdicts(&self) -> Vec<Verdict> {
+        match &self.run.parts {
+            Ok(parts) => parts
+                .iter()
+                .map(|part| match (&self.expected, self.expected(part.part)) {
+                    (Err(_), _) => Verdict::Error,
+                    _ if part.answer.is_err() => Verdict::Error,
+                    (Ok(_), Some(expected)) if Ok(expected) == part.answer.as_deref() => {
+                        Verdict::Pass
+                    }
+                    (Ok(_), Some(_)) => Verdict::Fail,
+                    (Ok(_), None) => Verdict::Unregistered,
+                })
+                .collect(),
+            Err(_) => vec![Verdict::Error],
+        }
+    }
+}
+
+/// Solve both parts of `day` against its own input, without the cache so that
+/// the timings are real.
+pub fn report_day(day: &Day) -> DayReport {
+    let input = day.default_input();
+    let size = fs::read_to_string(&input)
+        .ok()
+        .map(|text| (text.lines().count(), text.len()));
+    let file_name = input.file_name().unwrap_or_default().to_string_lossy();
+    let expected = load_answers(Path::new(day.data_dir))
+        .map(|answers| {
+            answers
+                .into_iter()
+                .filter(|answer| answer.input == file_name)
+                .collect()
+        })
+        .map_err(|error| error.to_string());
+    DayReport {
+        run: summary::run_day(day, input.clone(), &Part::ALL, None, false),
+        size,
+        expected,
+    }
+}
+
+const HEADER: [&str; 8] = [
+    "Day", "Input", "Size", "Part", "Answer", "Expected", "Result", "Time",
+];
+
+/// The cells of the table of every report, one row per part.
+fn rows(reports: &[DayReport]) -> Vec<[String; 8]> {
+    let mut rows = vec![];
+    for report in reports {
+        let day = report.run.day.to_string();
+        let input = report
+            .run
+            .input
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        let size = match report.size {
+            Some((lines, bytes)) => format!("{lines} lines, {bytes} bytes"),
+            None => "-".to_string(),
+        };
+        match &report.run.parts {
+            Ok(parts) => {
+                for (part, verdict) in parts.iter().zip(report.verdicts()) {
+                    let expected = match &report.expected {
+                        Ok(_) => report.expected(part.part).unwrap_or("-").to_string(),
+                        Err(error) => format!("error: {error}"),
+                    };
+                    rows.push([
+                        day.clone(),
+                        input.clone(),
+                        size.clone(),
+                        part.part.to_string(),
+                        match &part.answer {
+                            Ok(answer) => answer.clone(),
+                            Err(error) => format!("error: {error}"),
+                        },
+                        expected,
+                        verdict.name().to_string(),
+                        format_duration(part.elapsed),
+                    ]);
+                }
+            }
+            Err(error) => rows.push([
+                day,
+                input,
+                size,
+                "-".to_string(),
+                format!("error: {error}"),
+                "-".to_string(),
+                Verdict::Error.name().to_string(),
+                "-".to_string(),
+            ]),
+        }
+    }
+    rows
+}
+
+/// The line under the title: how many answers were verified and how long
+/// solving everything took.
+fn format_totals(reports: &[DayReport], elapsed: Duration) -> String {
+    let verdicts: Vec<Verdict> = reports.iter().flat_map(DayReport::verdicts).collect();
+    let passed = verdicts.iter().filter(|v| **v == Verdict::Pass).count();
+    format!(
+        "{passed}/{} answers verified, in {}.",
+        verdicts.len(),
+        format_duration(elapsed)
+    )
+}
+
+pub fn format_markdown(reports: &[DayReport], elapsed: Duration) -> String {
+    let mut markdown = format!(
+        "# Advent of Code 2023\n\n{}\n\n",
+        format_totals(reports, elapsed)
+    );
+    let mut line = |cells: &[String]| {
+        // Pipes would end the cell early
+        let cells: Vec<String> = cells.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+    };
+    line(&HEADER.map(String::from));
+    line(&HEADER.map(|_| "---".to_string()));
+    for row in rows(reports) {
+        line(&row);
+    }
+    markdown
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: 0.3em 0.8em; text-align: left; }
+th { background: #f0f0f0; }
+.pass { color: #080; }
+.FAIL, .ERROR { color: #c00; font-weight: bold; }
+.unregistered { color: #888; }";
+
+/// A standalone page with the same table as [`format_markdown`], with the
+/// results colored.
+pub fn format_html(reports: &[DayReport], elapsed: Duration) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code 2023</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n\
+         <h1>Advent of Code 2023</h1>\n<p>{}</p>\n<table>\n",
+        escape_html(&format_totals(reports, elapsed))
+    );
+    let header: Vec<String> = HEADER
+        .iter()
+        .map(|cell| format!("<th>{cell}</th>"))
+        .collect();
+    html.push_str(&format!("<tr>{}</tr>\n", header.concat()));
+    for row in rows(reports) {
+        let cells: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| match i {
+                6 => format!("<td class=\"{cell}\">{cell}</td>"),
+                _ => format!("<td>{}</td>", escape_html(cell)),
+            })
+            .collect();
+        html.push_str(&format!("<tr>{}</tr>\n", cells.concat()));
+    }
+    html.push_str("</table>\n</body>\n</html>\n");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::{PartRun, RunError};
+    use aoc_common::ParseError;
+    use std::{io, path::PathBuf};
+
+    fn reports() -> Vec<DayReport> {
+        let answer = |part, answer: &str| Answer {
+            input: "input.txt".to_string(),
+            part,
+            answer: answer.to_string(),
+        };
+        let run = |part, answer: &str| PartRun {
+            part,
+            answer: Ok(answer.to_string()),
+            elapsed: Duration::from_millis(2),
+            cached: false,
+            overflow: None,
+        };
+        vec![
+            DayReport {
+                run: DayRun {
+                    day: 1,
+                    input: PathBuf::from("day1/data/input.txt"),
+                    parts: Ok(vec![run(Part::One, "142"), run(Part::Two, "<281>")]),
+                },
+                size: Some((4, 37)),
+                expected: Ok(vec![answer(Part::One, "142"), answer(Part::Two, "280")]),
+            },
+            DayReport {
+                run: DayRun {
+                    day: 2,
+                    input: PathBuf::from("day2/data/input.txt"),
+                    parts: Err(RunError::Parse(ParseError::Io {
+                        file: None,
+                        source: io::Error::new(io::ErrorKind::NotFound, "missing"),
+                    })),
+                },
+                size: None,
+                expected: Ok(vec![]),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format_markdown() {
+        assert_eq!(
+            format_markdown(&reports(), Duration::from_millis(5)),
+            "\
+# Advent of Code 2023
+
+1/3 answers verified, in 5.0ms.
+
+| Day | Input | Size | Part | Answer | Expected | Result | Time |
+| --- | --- | --- | --- | --- | --- | --- | --- |
+| 1 | input.txt | 4 lines, 37 bytes | 1 | 142 | 142 | pass | 2.0ms |
+| 1 | input.txt | 4 lines, 37 bytes | 2 | <281> | 280 | FAIL | 2.0ms |
+| 2 | input.txt | - | - | error: unable to read input: missing | - | ERROR | - |
+"
+        );
+    }
+
+    #[test]
+    fn test_format_html() {
+        let html = format_html(&reports(), Duration::from_millis(5));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<p>1/3 answers verified, in 5.0ms.</p>"));
+        assert!(html.contains("<td>&lt;281&gt;</td><td>280</td><td class=\"FAIL\">FAIL</td>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+}
@@ -0,0 +1,301 @@
+//! Generates the crate of a new day and wires it into the workspace and the
+//! runner.
+
+use std::{fs, path::Path};
+
+/// The root of the workspace the runner was built in.
+pub const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc-common = { path = "../aoc-common" }
+tracing = "0.1"
+"#;
+
+const LIB_RS: &str = r#"mod generate;
+
+use aoc_common::{input::lines, numeric::Uint, ParseError, Puzzle, SolveError};
+use tracing::{debug, instrument};
+
+/// Directory holding this day's example and puzzle inputs.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+pub struct Day{day} {
+    lines: Vec<String>,
+}
+
+impl Day{day} {
+    fn from_string(input: &str) -> Result<Self, ParseError> {
+        let lines: Vec<String> = lines(input).map(|line| line.text.to_string()).collect();
+        debug!(lines = lines.len(), "parsed the input");
+        Ok(Day{day} { lines })
+    }
+}
+
+impl Puzzle for Day{day} {
+    type Input = Self;
+    type Output = Uint;
+
+    #[instrument(skip_all)]
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day{day}::from_string(input)
+    }
+
+    #[instrument(skip_all)]
+    fn part1(input: &Self) -> Result<Uint, SolveError> {
+        Ok(input.lines.len() as Uint)
+    }
+
+    #[instrument(skip_all)]
+    fn part2(input: &Self) -> Result<Uint, SolveError> {
+        Ok(input.lines.len() as Uint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answers::assert_answers;
+
+    // Remove the `ignore` once the answers of the input are registered in
+    // data/answers.txt, which fails the test until then
+    #[test]
+    #[ignore = "no answers registered for example.txt yet"]
+    fn test_example() {
+        assert_answers::<Day{day}>(DATA_DIR, "example.txt");
+    }
+
+    #[test]
+    #[ignore = "no answers registered for input.txt yet"]
+    fn test_input() {
+        assert_answers::<Day{day}>(DATA_DIR, "input.txt");
+    }
+}
+"#;
+
+const GENERATE_RS: &str = r#"use crate::Day{day};
+use aoc_common::generate::{Generate, Rng};
+
+impl Generate for Day{day} {
+    /// `size` lines of random letters.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::new();
+        for _ in 0..size {
+            for _ in 0..rng.range(1..20) {
+                input.push(char::from(b'a' + rng.below(26) as u8));
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+"#;
+
+const ANSWERS_TXT: &str = "# <input file> <part> <answer>\n";
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{day}", &day.to_string())
+}
+
+/// Add `day{day}` to the members of the workspace manifest `manifest`.
+fn add_member(manifest: &str, day: u8) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or("the workspace has no members")?;
+    let end = start
+        + manifest[start..]
+            .find("\n]")
+            .ok_or("the workspace members aren't closed")?;
+    Ok(format!(
+        "{}\n    \"day{day}\",{}",
+        &manifest[..end],
+        &manifest[end..]
+    ))
+}
+
+/// Add `day{day}` to the dependencies of the runner's manifest `manifest`,
+/// which must be its last section.
+fn add_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    let start = manifest
+        .find("[dependencies]")
+        .ok_or("the runner has no dependencies")?;
+    let end = manifest[start..]
+        .find("\n[")
+        .map_or(manifest.len(), |i| start + i);
+    // Keep the days together, right after the last one
+    let mut at = start + manifest[start..end].trim_end().len();
+    let mut offset = start;
+    for line in manifest[start..end].split_inclusive('\n') {
+        offset += line.len();
+        if line.starts_with("day") {
+            at = offset - 1;
+        }
+    }
+    Ok(format!(
+        "{}\nday{day} = {{ path = \"../day{day}\" }}{}",
+        &manifest[..at],
+        &manifest[at..]
+    ))
+}
+
+/// Add `day{day}` to the table of days in the runner's `days.rs`.
+fn add_day(days: &str, day: u8) -> Result<String, String> {
+    let start = days
+        .find("pub const DAYS")
+        .ok_or("there's no table of days")?;
+    let end = start
+        + days[start..]
+            .find("\n];")
+            .ok_or("the table of days isn't closed")?;
+    Ok(format!(
+        "{}\n    Day::new::<day{day}::Day{day}>({day}, day{day}::DATA_DIR),{}",
+        &days[..end],
+        &days[end..]
+    ))
+}
+
+fn edit<F>(path: &Path, change: F) -> Result<(), String>
+where
+    F: FnOnce(&str) -> Result<String, String>,
+{
+    let text = fs::read_to_string(path)
+        .map_err(|error| format!("unable to read {}: {}", path.display(), error))?;
+    let text = change(&text).map_err(|error| format!("{}: {}", path.display(), error))?;
+    fs::write(path, text).map_err(|error| format!("unable to write {}: {}", path.display(), error))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|error| format!("unable to create {}: {}", dir.display(), error))?;
+    }
+    fs::write(path, text).map_err(|error| format!("unable to write {}: {}", path.display(), error))
+}
+
+/// Generate the crate of `day` in the workspace at `root`, and wire it into
+/// the workspace and the runner. Nothing is generated if the crate exists.
+pub fn new_day(root: &Path, day: u8) -> Result<(), String> {
+    let crate_dir = root.join(format!("day{day}"));
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+    write(&crate_dir.join("Cargo.toml"), &render(CARGO_TOML, day))?;
+    write(&crate_dir.join("src/lib.rs"), &render(LIB_RS, day))?;
+    write(
+        &crate_dir.join("src/generate.rs"),
+        &render(GENERATE_RS, day),
+    )?;
+    write(&crate_dir.join("data/example.txt"), "")?;
+    write(&crate_dir.join("data/input.txt"), "")?;
+    write(&crate_dir.join("data/answers.txt"), ANSWERS_TXT)?;
+    edit(&root.join("Cargo.toml"), |text| add_member(text, day))?;
+    edit(&root.join("aoc/Cargo.toml"), |text| {
+        add_dependency(text, day)
+    })?;
+    edit(&root.join("aoc/src/days.rs"), |text| add_day(text, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member() {
+        let manifest =
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day9\",\n]\n\n[profile.test]\n";
+        assert_eq!(
+            add_member(manifest, 10).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day9\",\n    \"day10\",\n]\n\n[profile.test]\n"
+        );
+        assert!(add_member("[package]\n", 10).is_err());
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        assert_eq!(
+            add_dependency("[dependencies]\nday9 = { path = \"../day9\" }\n", 10).unwrap(),
+            "[dependencies]\nday9 = { path = \"../day9\" }\nday10 = { path = \"../day10\" }\n"
+        );
+        assert_eq!(
+            add_dependency(
+                "[dependencies]\nday9 = { path = \"../day9\" }\ntracing = \"0.1\"\n\n[features]\n",
+                10
+            )
+            .unwrap(),
+            "[dependencies]\nday9 = { path = \"../day9\" }\nday10 = { path = \"../day10\" }\n\
+             tracing = \"0.1\"\n\n[features]\n"
+        );
+        assert_eq!(
+            add_dependency("[dependencies]\n\n[features]\n", 1).unwrap(),
+            "[dependencies]\nday1 = { path = \"../day1\" }\n\n[features]\n"
+        );
+        assert!(add_dependency("[package]\n", 10).is_err());
+    }
+
+    #[test]
+    fn test_add_day() {
+        let days = "pub const DAYS: &[Day] = &[\n    Day::new::<day9::OasisReport>(9, day9::DATA_DIR),\n];\n";
+        assert_eq!(
+            add_day(days, 10).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    Day::new::<day9::OasisReport>(9, day9::DATA_DIR),\n    Day::new::<day10::Day10>(10, day10::DATA_DIR),\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let real_root = Path::new(WORKSPACE_DIR);
+        for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+            let text = fs::read_to_string(real_root.join(file)).unwrap();
+            write(&root.join(file), &text).unwrap();
+        }
+        new_day(&root, 42).unwrap();
+        let lib = fs::read_to_string(root.join("day42/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Puzzle for Day42 {"));
+        assert!(root.join("day42/src/generate.rs").is_file());
+        assert!(root.join("day42/data/input.txt").is_file());
+        let days = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(days.contains("Day::new::<day42::Day42>(42, day42::DATA_DIR),\n];"));
+        assert!(new_day(&root, 42).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    /// The generated crate builds, and its tests pass, in a workspace of its
+    /// own next to the real `aoc-common`.
+    #[cfg(unix)]
+    #[test]
+    #[ignore = "runs a nested cargo build; test_new_day covers the file edits"]
+    fn test_new_day_builds() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-build-{}", std::process::id()));
+        let real_root = Path::new(WORKSPACE_DIR);
+        write(&root.join("Cargo.toml"), "[workspace]\nmembers = [\n]\n").unwrap();
+        for file in ["Cargo.lock", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+            let text = fs::read_to_string(real_root.join(file)).unwrap();
+            write(&root.join(file), &text).unwrap();
+        }
+        std::os::unix::fs::symlink(real_root.join("aoc-common"), root.join("aoc-common")).unwrap();
+        new_day(&root, 42).unwrap();
+        let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+        let output = std::process::Command::new(cargo)
+            .args(["test", "--offline", "--quiet", "-p", "day42"])
+            .current_dir(&root)
+            // Keep the dependencies built between runs, away from the lock
+            // of the build running this test
+            .env("CARGO_TARGET_DIR", real_root.join("target/aoc-scaffold"))
+            .output()
+            .unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("2 ignored"), "{stdout}");
+    }
+}
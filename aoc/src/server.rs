@@ -0,0 +1,408 @@
+//! A local HTTP API serving the day solvers, for tools that would rather not
+//! run `aoc`.
+//!
+//! `POST /day/<N>/part/<1|2>` with the puzzle input as the body answers with a
+//! JSON object holding the day, the part and either the answer and the time it
+//! took to parse and solve the input, or the error. Integer answers are JSON
+//! numbers, like in `aoc run --format json`. A solver that panics answers
+//! with a 500, and one that takes too long with a 503, as does any request
+//! while too many inputs are still being solved.
+
+use crate::{days::Day, isolate::isolate, json::Object};
+use aoc_common::Part;
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    str,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The port the server listens on unless told otherwise.
+pub const DEFAULT_PORT: u16 = 2023;
+
+/// The largest request line and headers accepted, all together.
+const MAX_HEAD: u64 = 16 << 10;
+
+/// The largest puzzle input accepted, far above any real one.
+const MAX_BODY: usize = 16 << 20;
+
+/// How long a puzzle may take to parse and solve, far above any real one.
+const SOLVE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long a client may take to send a request or to read the response.
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How many inputs may be parsed and solved at once. Solvers that time out
+/// keep running, so they count until they're done.
+const MAX_SOLVING: usize = 16;
+
+static SOLVERS: Solvers = Solvers::new(MAX_SOLVING);
+
+/// Counts the solvers running, up to a limit.
+struct Solvers {
+    running: AtomicUsize,
+    max: usize,
+}
+
+impl Solvers {
+    const fn new(max: usize) -> Self {
+        Solvers {
+            running: AtomicUsize::new(0),
+            max,
+        }
+    }
+
+    /// A place for one more solver, unless there are already too many.
+    fn claim(&'static self) -> Option<Slot> {
+        let claimed = self
+            .running
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |running| {
+                (running < self.max).then_some(running + 1)
+            });
+        claimed.ok().map(|_| Slot(self))
+    }
+}
+
+/// The place of a running solver, given back when it's dropped.
+struct Slot(&'static Solvers);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.running.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    /// A JSON object.
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: Object) -> Self {
+        Response {
+            status,
+            body: body.to_string(),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Response::json(status, Object::new().string("error", message))
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+/// The day and part numbers of a `/day/<N>/part/<P>` path.
+fn route(path: &str) -> Option<(u8, u8)> {
+    let (day, part) = path.strip_prefix("/day/")?.split_once("/part/")?;
+    Some((day.parse().ok()?, part.parse().ok()?))
+}
+
+/// Answer a `method` request on `path`, whose body is `body`.
+pub fn respond(method: &str, path: &str, body: &[u8]) -> Response {
+    let Some((number, part)) = route(path) else {
+        return Response::error(404, &format!("no route for `{path}`"));
+    };
+    if method != "POST" {
+        return Response::error(405, "the puzzle input must be POSTed");
+    }
+    let Some(day) = Day::find(number) else {
+        return Response::error(404, &format!("day {number} isn't solved yet"));
+    };
+    let Some(part) = Part::from_number(part) else {
+        return Response::error(404, &format!("part {part} doesn't exist"));
+    };
+    let Ok(input) = str::from_utf8(body) else {
+        return Response::error(400, "the puzzle input isn't UTF-8");
+    };
+    solve(&SOLVERS, day, part, input.to_string(), SOLVE_TIMEOUT)
+}
+
+/// Parse `input` and solve `part` of `day` on a thread of its own, so that
+/// a solver that panics or takes longer than `timeout` doesn't take the
+/// request down with it, unless `solvers` are all taken.
+fn solve(
+    solvers: &'static Solvers,
+    day: &'static Day,
+    part: Part,
+    input: String,
+    timeout: Duration,
+) -> Response {
+    let result = Object::new().number("day", day.number).number("part", part);
+    let Some(slot) = solvers.claim() else {
+        return Response::json(
+            503,
+            result.string("error", "too many inputs are being solved, try again later"),
+        );
+    };
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let start = Instant::now();
+        let result = isolate(|| (day.parse)(&input).map(|solution| solution.solve(part)));
+        // Give the slot back before answering, so that it's free once answered
+        drop(slot);
+        // The request may have timed out and stopped waiting
+        let _ = sender.send((result, start.elapsed()));
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok((Ok(Ok(Ok(answer))), elapsed)) => Response::json(
+            200,
+            result
+                .number_or_string("answer", &answer)
+                .number("elapsed_ms", elapsed.as_secs_f64() * 1e3),
+        ),
+        Ok((Ok(Ok(Err(error))), _)) => {
+            Response::json(422, result.string("error", &error.to_string()))
+        }
+        Ok((Ok(Err(error)), _)) => Response::json(422, result.string("error", &error.to_string())),
+        Ok((Err(message), _)) => Response::json(500, result.string("error", &message)),
+        // There's no stopping the solver, which keeps its thread until it's
+        // done
+        Err(_) => Response::json(
+            503,
+            result.string(
+                "error",
+                &format!("no answer after {}s", timeout.as_secs_f64()),
+            ),
+        ),
+    }
+}
+
+/// The next line of the request line and headers, or `None` if it goes past
+/// their limit.
+fn head_line<R>(head: &mut io::Take<R>) -> io::Result<Option<String>>
+where
+    R: BufRead,
+{
+    let mut line = String::new();
+    head.read_line(&mut line)?;
+    // Only a line cut short by the limit is both unfinished and at the limit
+    Ok((line.ends_with('\n') || head.limit() > 0).then_some(line))
+}
+
+/// Read one request from `stream` and write back its response.
+fn handle(stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let mut head = (&mut reader).take(MAX_HEAD);
+    let request_line = head_line(&mut head)?.unwrap_or_default();
+    let mut fields = request_line.split_whitespace();
+    let (method, path) = (fields.next().unwrap_or(""), fields.next().unwrap_or(""));
+    let mut length = Some(0);
+    let mut too_large = false;
+    loop {
+        let Some(header) = head_line(&mut head)? else {
+            too_large = true;
+            break;
+        };
+        if header.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+    let response = match length {
+        _ if too_large => Response::error(431, "the request headers are too large"),
+        _ if path.is_empty() => Response::error(400, "malformed request"),
+        None => Response::error(400, "malformed `Content-Length`"),
+        Some(length) if length > MAX_BODY => Response::error(413, "the puzzle input is too large"),
+        Some(length) => {
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body)?;
+            respond(method, path, &body)
+        }
+    };
+    write!(
+        &stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    )
+}
+
+/// Listen on `port` of the loopback interface only, so that the solvers
+/// aren't exposed to the network. Port 0 picks any free port.
+pub fn bind(port: u16) -> io::Result<TcpListener> {
+    TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+}
+
+/// Answer the requests made to `listener`, each on its own thread, forever.
+/// A connection that fails before it's accepted is only logged.
+pub fn serve(listener: TcpListener) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(error) => {
+                eprintln!("error: unable to accept a connection: {error}");
+                continue;
+            }
+        };
+        thread::spawn(move || {
+            // The client went away, so there's no one to tell
+            let _ = handle(stream);
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::PANICKING;
+    use std::{io::Cursor, net::SocketAddr};
+
+    const RACES: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+    /// Send a request the way any HTTP client would, returning the status and
+    /// the body of the response.
+    fn request(address: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, body.to_string())
+    }
+
+    #[test]
+    fn test_respond() {
+        let response = respond("POST", "/day/6/part/1", RACES.as_bytes());
+        assert_eq!(response.status, 200);
+        assert!(response
+            .body
+            .starts_with(r#"{"day": 6, "part": 1, "answer": 288, "elapsed_ms": "#));
+        assert_eq!(
+            respond("POST", "/day/6/part/1", b"Time: 7\n"),
+            Response {
+                status: 422,
+                body: r#"{"day": 6, "part": 1, "error": "line 2, column 1: expected the record distances, found the end of the input"}"#
+                    .to_string()
+            }
+        );
+        assert_eq!(respond("GET", "/day/6/part/1", b"").status, 405);
+        assert_eq!(respond("POST", "/day/6/part/3", b"").status, 404);
+        assert_eq!(respond("POST", "/day/30/part/1", b"").status, 404);
+        assert_eq!(respond("POST", "/days", b"").status, 404);
+        assert_eq!(respond("POST", "/day/6/part/1", b"\xff").status, 400);
+    }
+
+    #[test]
+    fn test_solve_isolated() {
+        assert_eq!(
+            solve(
+                &SOLVERS,
+                &PANICKING,
+                Part::One,
+                RACES.to_string(),
+                SOLVE_TIMEOUT
+            ),
+            Response {
+                status: 500,
+                body: r#"{"day": 0, "part": 1, "error": "panicked: the parser gave up"}"#
+                    .to_string()
+            }
+        );
+        const SLOW: Day = Day {
+            parse: |input| {
+                thread::sleep(Duration::from_millis(500));
+                (PANICKING.parse)(input)
+            },
+            ..PANICKING
+        };
+        let timeout = Duration::from_millis(50);
+        assert_eq!(
+            solve(&SOLVERS, &SLOW, Part::Two, RACES.to_string(), timeout),
+            Response {
+                status: 503,
+                body: r#"{"day": 0, "part": 2, "error": "no answer after 0.05s"}"#.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_solve_limited() {
+        static ONE: Solvers = Solvers::new(1);
+        const SLOW: Day = Day {
+            parse: |input| {
+                thread::sleep(Duration::from_millis(300));
+                (PANICKING.parse)(input)
+            },
+            ..PANICKING
+        };
+        let timeout = Duration::from_millis(50);
+        assert_eq!(
+            solve(&ONE, &SLOW, Part::One, RACES.to_string(), timeout).status,
+            503
+        );
+        // The solver that timed out still runs
+        assert_eq!(
+            solve(&ONE, &SLOW, Part::One, RACES.to_string(), timeout),
+            Response {
+                status: 503,
+                body: r#"{"day": 0, "part": 1, "error": "too many inputs are being solved, try again later"}"#
+                    .to_string()
+            }
+        );
+        thread::sleep(Duration::from_millis(500));
+        assert_eq!(
+            solve(&ONE, &SLOW, Part::One, RACES.to_string(), SOLVE_TIMEOUT).status,
+            500
+        );
+        assert_eq!(ONE.running.load(Ordering::Acquire), 0);
+    }
+
+    #[test]
+    fn test_head_line() {
+        let line = |text: &str, limit| head_line(&mut Cursor::new(text).take(limit)).unwrap();
+        assert_eq!(line("Host: a\r\nX", 9), Some("Host: a\r\n".to_string()));
+        assert_eq!(line("Host: a\r\nX", 8), None);
+        // The end of the request isn't the end of the limit
+        assert_eq!(line("Host", 9), Some("Host".to_string()));
+    }
+
+    #[test]
+    fn test_serve() {
+        let listener = bind(0).unwrap();
+        let address = listener.local_addr().unwrap();
+        assert!(address.ip().is_loopback());
+        thread::spawn(move || serve(listener));
+        let (status, body) = request(address, "POST", "/day/6/part/2", RACES);
+        assert_eq!(status, 200);
+        assert!(body.contains(r#""answer": 71503,"#), "{body}");
+        let (status, body) = request(address, "POST", "/day/6/part/2", "Time: 7\n");
+        assert_eq!(status, 422);
+        assert!(body.contains(r#""error": "line 2, column 1"#), "{body}");
+    }
+}
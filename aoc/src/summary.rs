@@ -0,0 +1,302 @@
+//! Solving days while timing every part, and the summary of such runs.
+
+use crate::{
+    bench::format_duration,
+    cache::{Cache, Key},
+    days::Day,
+};
+use aoc_common::{
+    input::load_input, numeric::Overflow, table::format_table, ParseError, Part, SolveError,
+};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// How many of the slowest parts the summary points out.
+const SLOWEST: usize = 3;
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: Part,
+    /// The answer, or why the part has none.
+    pub answer: Result<String, SolveError>,
+    pub elapsed: Duration,
+    /// Whether the answer was found in the cache instead of being solved.
+    pub cached: bool,
+    /// Where the answer overflowed, if it did in checked mode, which then
+    /// reports it instead of `answer`'s error.
+    pub overflow: Option<Overflow>,
+}
+
+/// Why a day has no answers at all.
+#[derive(Debug)]
+pub enum RunError {
+    Parse(ParseError),
+    /// The day panicked, with this message.
+    Panicked(String),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Parse(error) => write!(f, "{error}"),
+            RunError::Panicked(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl From<ParseError> for RunError {
+    fn from(error: ParseError) -> Self {
+        RunError::Parse(error)
+    }
+}
+
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    pub input: PathBuf,
+    /// The answer of every part that was run, or why there are none.
+    pub parts: Result<Vec<PartRun>, RunError>,
+}
+
+impl DayRun {
+    /// The run of `day` against `input` that panicked with `message`.
+    pub fn panicked(day: &Day, input: PathBuf, message: String) -> Self {
+        DayRun {
+            day: day.number,
+            input,
+            parts: Err(RunError::Panicked(message)),
+        }
+    }
+}
+
+/// Solve `parts` of `day` against `input`, unless their answers are found in
+/// `cache`. Solved answers are added to the cache. In `checked` mode the parts
+/// that overflow say so apart from other errors.
+pub fn run_day(
+    day: &Day,
+    input: PathBuf,
+    parts: &[Part],
+    cache: Option<&Cache>,
+    checked: bool,
+) -> DayRun {
+    DayRun {
+        day: day.number,
+        parts: solve_parts(day, &input, parts, cache, checked),
+        input,
+    }
+}
+
+fn solve_parts(
+    day: &Day,
+    input: &Path,
+    parts: &[Part],
+    cache: Option<&Cache>,
+    checked: bool,
+) -> Result<Vec<PartRun>, RunError> {
+    let text = load_input(input)?;
+    let keys: Vec<Key> = parts
+        .iter()
+        .map(|&part| Key::new(day.number, part, day.version, &text))
+        .collect();
+    let cached: Vec<Option<String>> = keys
+        .iter()
+        .map(|key| cache.and_then(|cache| cache.get(key)))
+        .collect();
+    // Only parse the input if some part has to be solved
+    let solution = if cached.iter().all(Option::is_some) {
+        None
+    } else {
+        Some((day.parse)(&text).map_err(|error| error.with_file(input))?)
+    };
+    let mut runs = vec![];
+    for ((&part, key), cached) in parts.iter().zip(&keys).zip(cached) {
+        let start = Instant::now();
+        let run = match (cached, &solution) {
+            (Some(answer), _) => PartRun {
+                part,
+                answer: Ok(answer),
+                elapsed: start.elapsed(),
+                cached: true,
+                overflow: None,
+            },
+            (None, Some(solution)) => {
+                let answer = solution.solve(part);
+                let elapsed = start.elapsed();
+                if let (Some(cache), Ok(answer)) = (cache, &answer) {
+                    // A cache that can't be written to only costs time later
+                    let _ = cache.put(key, answer);
+                }
+                let overflow = match &answer {
+                    Err(SolveError::Overflow(overflow)) if checked => Some(overflow.clone()),
+                    _ => None,
+                };
+                PartRun {
+                    part,
+                    answer,
+                    elapsed,
+                    cached: false,
+                    overflow,
+                }
+            }
+            (None, None) => unreachable!("the input is parsed when a part isn't cached"),
+        };
+        runs.push(run);
+    }
+    Ok(runs)
+}
+
+/// Lay out `runs` as one table, pointing out the slowest parts, followed by
+/// the `wall` time it took to run them all on `jobs` threads.
+pub fn format_summary(runs: &[DayRun], wall: Duration, jobs: usize) -> String {
+    let mut times: Vec<Duration> = runs
+        .iter()
+        .filter_map(|run| run.parts.as_ref().ok())
+        .flatten()
+        .map(|part| part.elapsed)
+        .collect();
+    times.sort_unstable_by(|a, b| b.cmp(a));
+    let slowest = times.get(SLOWEST.min(times.len()).saturating_sub(1));
+    let mut rows = vec![vec![
+        "Day".to_string(),
+        "Part".to_string(),
+        "Answer".to_string(),
+        "Time".to_string(),
+        String::new(),
+    ]];
+    for run in runs {
+        match &run.parts {
+            Ok(parts) => {
+                for part in parts {
+                    let marker = match slowest {
+                        _ if part.cached => "(cached)",
+                        Some(slowest) if part.elapsed >= *slowest => "<- slowest",
+                        _ => "",
+                    };
+                    rows.push(vec![
+                        run.day.to_string(),
+                        part.part.to_string(),
+                        match (&part.overflow, &part.answer) {
+                            (Some(overflow), _) => format!("overflow at {overflow}"),
+                            (None, Ok(answer)) => answer.clone(),
+                            (None, Err(error)) => format!("error: {error}"),
+                        },
+                        format_duration(part.elapsed),
+                        marker.to_string(),
+                    ]);
+                }
+            }
+            Err(error) => rows.push(vec![
+                run.day.to_string(),
+                "-".to_string(),
+                format!("error: {error}"),
+                String::new(),
+                String::new(),
+            ]),
+        }
+    }
+    let solving: Duration = times.iter().sum();
+    format!(
+        "{}\nTotal: {} wall time on {} thread{}, {} spent solving\n",
+        format_table(&rows),
+        format_duration(wall),
+        jobs,
+        if jobs == 1 { "" } else { "s" },
+        format_duration(solving)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(day: u8, millis: &[u64]) -> DayRun {
+        DayRun {
+            day,
+            input: PathBuf::from("input.txt"),
+            parts: Ok(Part::ALL
+                .iter()
+                .zip(millis)
+                .map(|(&part, &ms)| PartRun {
+                    part,
+                    answer: Ok((ms * 10).to_string()),
+                    elapsed: Duration::from_millis(ms),
+                    cached: false,
+                    overflow: None,
+                })
+                .collect()),
+        }
+    }
+
+    #[test]
+    fn test_format_summary() {
+        let mut runs = [run(1, &[1, 2]), run(2, &[30, 4])];
+        let overflow = Overflow {
+            at: "line 1".to_string(),
+            operation: "18446744073709551615 + 1".to_string(),
+            r#type: "u64",
+        };
+        runs[0].parts.as_mut().unwrap()[0] = PartRun {
+            part: Part::One,
+            answer: Err(SolveError::Overflow(overflow.clone())),
+            elapsed: Duration::from_millis(1),
+            cached: false,
+            overflow: Some(overflow),
+        };
+        assert_eq!(
+            format_summary(&runs, Duration::from_millis(32), 2),
+            "\
+Day  Part  Answer                                                      Time
+1    1     overflow at line 1: 18446744073709551615 + 1 overflows u64  1.0ms
+1    2     20                                                          2.0ms   <- slowest
+2    1     300                                                         30.0ms  <- slowest
+2    2     40                                                          4.0ms   <- slowest
+
+Total: 32.0ms wall time on 2 threads, 37.0ms spent solving
+"
+        );
+    }
+
+    #[test]
+    fn test_run_overflowing_day() {
+        // Every card wins copies of the next ten, which double for each card
+        let input: String = (1..=200)
+            .map(|id| format!("Card {id}: 1 2 3 4 5 6 7 8 9 10 | 1 2 3 4 5 6 7 8 9 10\n"))
+            .collect();
+        let path = std::env::temp_dir().join(format!("aoc-overflow-{}.txt", std::process::id()));
+        std::fs::write(&path, input).unwrap();
+        let day = Day::find(4).unwrap();
+        for checked in [false, true] {
+            let run = run_day(day, path.clone(), &Part::ALL, None, checked);
+            let parts = run.parts.unwrap();
+            assert_eq!(parts[0].answer.as_deref(), Ok("102400"));
+            assert_eq!(parts[0].overflow, None);
+            let Err(SolveError::Overflow(overflow)) = &parts[1].answer else {
+                panic!("part 2 doesn't overflow: {:?}", parts[1].answer);
+            };
+            assert_eq!(parts[1].overflow.as_ref(), checked.then_some(overflow));
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_format_panicked() {
+        let day = Day::find(3).unwrap();
+        let runs = [
+            run(1, &[1]),
+            DayRun::panicked(day, day.default_input(), "panicked: oops".to_string()),
+        ];
+        assert_eq!(
+            format_summary(&runs, Duration::from_millis(2), 2),
+            "\
+Day  Part  Answer                 Time
+1    1     10                     1.0ms  <- slowest
+3    -     error: panicked: oops
+
+Total: 2.0ms wall time on 2 threads, 1.0ms spent solving
+"
+        );
+    }
+}
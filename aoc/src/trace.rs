@@ -0,0 +1,37 @@
+//! Prints what the solvers trace while they run, to see how an answer came to
+//! be.
+
+use std::io;
+use tracing_subscriber::{filter::Targets, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+
+/// Read a `--trace` filter: a level such as `debug`, which applies to every
+/// day, and/or `day<N>=<level>` directives separated by commas.
+pub fn parse_filter(filter: &str) -> Result<Targets, String> {
+    filter
+        .parse::<Targets>()
+        .map_err(|error| format!("invalid `--trace` filter `{filter}`: {error}"))
+}
+
+/// Print the spans and events allowed by `filter` to the standard error, so
+/// they don't mix with the answers.
+pub fn init(filter: Targets) {
+    tracing_subscriber::registry()
+        .with(fmt::layer().with_writer(io::stderr).without_time())
+        .with(filter)
+        .init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::Level;
+
+    #[test]
+    fn test_parse_filter() {
+        let filter = parse_filter("info,day5=trace").unwrap();
+        assert!(filter.would_enable("day5", &Level::TRACE));
+        assert!(filter.would_enable("day8", &Level::INFO));
+        assert!(!filter.would_enable("day8", &Level::DEBUG));
+        assert!(parse_filter("day5=loud").is_err());
+    }
+}
@@ -0,0 +1,150 @@
+//! Re-runs a day whenever one of its inputs changes, showing how the answers
+//! changed.
+
+use crate::{
+    batch::{inputs, run_input, Row},
+    days::Day,
+};
+use aoc_common::Part;
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often the inputs are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// When every input of a directory was last modified, and how long it is, so
+/// that changes within the same second aren't missed either.
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+fn snapshot(dir: &Path) -> io::Result<Snapshot> {
+    let mut snapshot = Snapshot::new();
+    for input in inputs(dir)? {
+        // Inputs removed since they were listed are simply left out
+        if let Ok(metadata) = fs::metadata(&input) {
+            snapshot.insert(input, (metadata.modified()?, metadata.len()));
+        }
+    }
+    Ok(snapshot)
+}
+
+/// The inputs of `new` that aren't in `old` or changed since.
+fn changed_inputs(old: &Snapshot, new: &Snapshot) -> Vec<PathBuf> {
+    new.iter()
+        .filter(|(input, stamp)| old.get(*input) != Some(stamp))
+        .map(|(input, _)| input.clone())
+        .collect()
+}
+
+/// Describe the answers of `row`, compared to the `previous` ones.
+fn describe(row: &Row, previous: Option<&Row>) -> String {
+    let mut lines = vec![format!("{} ({:.1?})", row.input, row.elapsed)];
+    match &row.answers {
+        Ok(answers) => {
+            for (part, answer) in Part::ALL.iter().zip(answers) {
+                let before = previous.and_then(|row| row.answers.as_ref().ok());
+                let change = match before {
+                    None => String::new(),
+                    Some(before) if before[part_index(*part)] == *answer => {
+                        " (unchanged)".to_string()
+                    }
+                    Some(before) => format!(" (was {})", before[part_index(*part)]),
+                };
+                lines.push(format!("  part {part}: {answer}{change}"));
+            }
+        }
+        Err(error) => lines.push(format!("  error: {error}")),
+    }
+    lines.join("\n")
+}
+
+fn part_index(part: Part) -> usize {
+    match part {
+        Part::One => 0,
+        Part::Two => 1,
+    }
+}
+
+/// Run `day` against every input of its data directory, and again against
+/// every input that changes, until the process is stopped. An input the day
+/// panics on shows the panic instead of its answers, and is run again once
+/// it changes like any other.
+pub fn watch(day: &Day) -> io::Result<()> {
+    let dir = Path::new(day.data_dir);
+    println!("Watching {} for changes", dir.display());
+    let mut seen = Snapshot::new();
+    let mut rows: BTreeMap<PathBuf, Row> = BTreeMap::new();
+    loop {
+        let current = snapshot(dir)?;
+        for input in changed_inputs(&seen, &current) {
+            let row = run_input(day, &input);
+            println!("\n{}", describe(&row, rows.get(&input)));
+            rows.insert(input, row);
+        }
+        rows.retain(|input, _| current.contains_key(input));
+        seen = current;
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::PANICKING;
+
+    fn row(answers: Result<[&str; 2], &str>) -> Row {
+        Row {
+            day: 1,
+            input: "example.txt".to_string(),
+            answers: answers
+                .map(|answers| answers.map(String::from))
+                .map_err(String::from),
+            elapsed: Duration::from_micros(1500),
+        }
+    }
+
+    #[test]
+    fn test_changed_inputs() {
+        let stamp = |secs, len| (SystemTime::UNIX_EPOCH + Duration::from_secs(secs), len);
+        let old = Snapshot::from([
+            (PathBuf::from("example.txt"), stamp(1, 10)),
+            (PathBuf::from("input.txt"), stamp(1, 10)),
+        ]);
+        let new = Snapshot::from([
+            (PathBuf::from("example.txt"), stamp(1, 10)),
+            (PathBuf::from("input.txt"), stamp(1, 11)),
+            (PathBuf::from("other.txt"), stamp(2, 10)),
+        ]);
+        assert_eq!(
+            changed_inputs(&old, &new),
+            vec![PathBuf::from("input.txt"), PathBuf::from("other.txt")]
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        let first = row(Ok(["142", "281"]));
+        assert_eq!(
+            describe(&first, None),
+            "example.txt (1.5ms)\n  part 1: 142\n  part 2: 281"
+        );
+        assert_eq!(
+            describe(&row(Ok(["140", "281"])), Some(&first)),
+            "example.txt (1.5ms)\n  part 1: 140 (was 142)\n  part 2: 281 (unchanged)"
+        );
+        assert_eq!(
+            describe(&row(Err("example.txt:2:1: oops")), Some(&first)),
+            "example.txt (1.5ms)\n  error: example.txt:2:1: oops"
+        );
+        let input = Path::new(Day::find(1).unwrap().data_dir).join("example1.txt");
+        let panicked = run_input(&PANICKING, &input);
+        assert!(
+            describe(&panicked, Some(&first)).ends_with("\n  error: panicked: the parser gave up"),
+            "{panicked:?}"
+        );
+    }
+}
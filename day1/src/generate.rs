@@ -0,0 +1,49 @@
+use crate::CalibrationDocument;
+use aoc_common::generate::{Generate, Rng};
+
+const WORDS: &[&str] = &[
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+impl Generate for CalibrationDocument {
+    /// `size` lines of letters, digits and spelled digits. Every line has at
+    /// least one numeric digit.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::new();
+        for _ in 0..size {
+            let pieces = rng.range(1..8);
+            let digit = rng.below(pieces);
+            for piece in 0..pieces {
+                if piece == digit || rng.chance(0.2) {
+                    input.push(char::from(b'1' + rng.below(9) as u8));
+                } else if rng.chance(0.3) {
+                    let word = rng.choose(WORDS);
+                    input.push_str(word);
+                } else {
+                    for _ in 0..rng.range(1..4) {
+                        input.push(char::from(b'a' + rng.below(26) as u8));
+                    }
+                }
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        let input = CalibrationDocument::generate(200, 1);
+        assert_eq!(input, CalibrationDocument::generate(200, 1));
+        assert_eq!(input.lines().count(), 200);
+        let document = CalibrationDocument::parse(&input).unwrap();
+        assert!(CalibrationDocument::part1(&document).unwrap() >= 200 * 11);
+        assert!(CalibrationDocument::part2(&document).unwrap() >= 200 * 11);
+    }
+}
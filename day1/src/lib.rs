@@ -0,0 +1,199 @@
+mod generate;
+
+use aoc_common::{
+    explain::{Combine, Contribution, Explanation},
+    numeric::{self, Overflow, Uint},
+    query::{self, Query, Reply},
+    ParseError, Part, Puzzle, SolveError,
+};
+use tracing::{instrument, trace};
+
+const DAY_1_DIGITS: &[&str] = &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+const DAY_2_DIGITS: &[&str] = &[
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "one", "two", "three", "four", "five", "six",
+    "seven", "eight", "nine",
+];
+
+/// Directory holding this day's example and puzzle inputs.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+pub struct CalibrationDocument {
+    lines: Vec<String>,
+}
+
+impl CalibrationDocument {
+    fn sum_calibration_values(&self, digits: &[&str]) -> Result<Uint, Overflow> {
+        let mut sum = 0;
+        for (i, line) in self.lines.iter().enumerate() {
+            let value = calibration_value(line, digits);
+            trace!(line, ?value, "calibration value");
+            if let Some(value) = value {
+                sum = numeric::add(sum, value.into(), || format!("line {}", i + 1))?;
+            }
+        }
+        Ok(sum)
+    }
+}
+
+impl Puzzle for CalibrationDocument {
+    type Input = Self;
+    type Output = Uint;
+    const QUERIES: &'static [Query] = &[Query {
+        usage: "line <n>",
+        help: "the calibration values of line n",
+    }];
+
+    #[instrument(skip_all)]
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let lines = input.lines().map(String::from).collect();
+        Ok(CalibrationDocument { lines })
+    }
+
+    /// The sum of all of the calibration values, only looking at numeric digits.
+    #[instrument(skip_all)]
+    fn part1(document: &Self) -> Result<Uint, SolveError> {
+        Ok(document.sum_calibration_values(DAY_1_DIGITS)?)
+    }
+
+    /// The sum of all of the calibration values, also looking at spelled digits.
+    #[instrument(skip_all)]
+    fn part2(document: &Self) -> Result<Uint, SolveError> {
+        Ok(document.sum_calibration_values(DAY_2_DIGITS)?)
+    }
+
+    /// The calibration value of every line.
+    fn explain(document: &Self, part: Part) -> Result<Option<Explanation>, SolveError> {
+        let digits = match part {
+            Part::One => DAY_1_DIGITS,
+            Part::Two => DAY_2_DIGITS,
+        };
+        let contributions = document
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let value = calibration_value(line, digits);
+                let detail = match value {
+                    Some(_) => line.clone(),
+                    None => format!("{line} has no digits"),
+                };
+                Contribution::new(format!("Line {}", i + 1), detail, value.unwrap_or(0))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Some(Explanation::new(Combine::Sum, contributions)))
+    }
+
+    fn query(document: &Self, words: &[&str]) -> Option<Reply> {
+        let ["line", n] = words else {
+            return None;
+        };
+        Some(query::nth(&document.lines, n, "line").map(|line| {
+            let value = |digits| match calibration_value(line, digits) {
+                Some(value) => value.to_string(),
+                None => "none".to_string(),
+            };
+            format!(
+                "{line}: {} with numeric digits, {} with spelled digits",
+                value(DAY_1_DIGITS),
+                value(DAY_2_DIGITS)
+            )
+        }))
+    }
+}
+
+fn calibration_value(line: &str, digits: &[&str]) -> Option<u32> {
+    // Get first digit from `line`
+    let mut first_digit = None;
+    let mut first_digit_position = None;
+    for digit in digits {
+        if let Some(pos) = line.find(digit) {
+            if first_digit_position.is_none() || pos < first_digit_position? {
+                first_digit_position = Some(pos);
+                first_digit = Some(digit);
+            }
+        }
+    }
+    // Return if none was found
+    first_digit?;
+    // Get the last digit from `line`
+    let mut last_digit = first_digit;
+    let mut last_digit_position = None;
+    for digit in digits {
+        if let Some(pos) = line.rfind(digit) {
+            if last_digit_position.is_none() || pos > last_digit_position? {
+                last_digit_position = Some(pos);
+                last_digit = Some(digit);
+            }
+        }
+    }
+    // Construct the final value
+    format!(
+        "{}{}",
+        translate_digit(first_digit?),
+        translate_digit(last_digit?)
+    )
+    .parse::<u32>()
+    .ok()
+}
+
+fn translate_digit(digit: &str) -> &str {
+    match digit {
+        "one" => "1",
+        "two" => "2",
+        "three" => "3",
+        "four" => "4",
+        "five" => "5",
+        "six" => "6",
+        "seven" => "7",
+        "eight" => "8",
+        "nine" => "9",
+        _ => digit,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::assert_answers, explain::assert_explained};
+
+    #[test]
+    fn test_example_1() {
+        assert_answers::<CalibrationDocument>(DATA_DIR, "example1.txt");
+    }
+
+    #[test]
+    fn test_example_2() {
+        assert_answers::<CalibrationDocument>(DATA_DIR, "example2.txt");
+    }
+
+    #[test]
+    fn test_input() {
+        assert_answers::<CalibrationDocument>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_explained::<CalibrationDocument>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_query() {
+        let document = CalibrationDocument::parse("two1nine\nabc\n").unwrap();
+        assert_eq!(
+            CalibrationDocument::query(&document, &["line", "1"]),
+            Some(Ok(
+                "two1nine: 11 with numeric digits, 29 with spelled digits".to_string()
+            ))
+        );
+        assert_eq!(
+            CalibrationDocument::query(&document, &["line", "2"]),
+            Some(Ok(
+                "abc: none with numeric digits, none with spelled digits".to_string()
+            ))
+        );
+        assert!(CalibrationDocument::query(&document, &["line", "3"])
+            .unwrap()
+            .is_err());
+        assert_eq!(CalibrationDocument::query(&document, &["lines"]), None);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::GameRecord;
+use aoc_common::generate::{Generate, Rng};
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+impl Generate for GameRecord {
+    /// A record of `size` games of up to six sets of cubes each.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::new();
+        for id in 1..=size {
+            let sets: Vec<String> = (0..rng.range(1..7))
+                .map(|_| {
+                    let mut colors = COLORS;
+                    rng.shuffle(&mut colors);
+                    let cubes: Vec<String> = colors[..rng.range(1..4) as usize]
+                        .iter()
+                        .map(|color| format!("{} {}", rng.range(1..21), color))
+                        .collect();
+                    cubes.join(", ")
+                })
+                .collect();
+            input.push_str(&format!("Game {}: {}\n", id, sets.join("; ")));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        let input = GameRecord::generate(300, 2);
+        assert_eq!(input, GameRecord::generate(300, 2));
+        let record = GameRecord::parse(&input).unwrap();
+        assert_eq!(record.games.len(), 300);
+        assert!(GameRecord::part1(&record).unwrap() > 0);
+        assert!(GameRecord::part2(&record).unwrap() > 0);
+    }
+}
@@ -0,0 +1,253 @@
+mod generate;
+
+use aoc_common::{
+    explain::{Combine, Contribution, Explanation},
+    input::{lines, Span},
+    numeric::{self, Overflow, Uint},
+    query::{self, Query, Reply},
+    ParseError, Part, Puzzle, SolveError,
+};
+use std::collections::HashMap;
+use tracing::{debug, instrument, trace};
+
+/// Directory holding this day's example and puzzle inputs.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+struct Cubes {
+    amount: u32,
+    color: String,
+}
+
+struct Game {
+    id: u32,
+    sets: Vec<Vec<Cubes>>,
+}
+
+impl Game {
+    fn from_line(line: Span) -> Result<Self, ParseError> {
+        // Input looks like: "Game id: amount color, amount color; amount color"
+        let (game, sets_unparsed) = line.split_once(':')?;
+        let id = game.strip_prefix("Game ")?.parse::<u32>()?;
+        let mut sets = vec![];
+        for set in sets_unparsed.split(';') {
+            let mut cubes = vec![];
+            for cube in set.split(',') {
+                let (amount_unparsed, color) = cube.trim().split_once(' ')?;
+                cubes.push(Cubes {
+                    amount: amount_unparsed.parse::<u32>()?,
+                    color: color.text.to_string(),
+                });
+            }
+            sets.push(cubes);
+        }
+        Ok(Game { id, sets })
+    }
+
+    fn cubes(&self) -> impl Iterator<Item = &Cubes> {
+        self.sets.iter().flatten()
+    }
+
+    fn is_possible(&self, elf_bag: &HashMap<String, u32>) -> bool {
+        // The game is possible if there are enough cubes in the elf's bag for
+        // every set
+        self.too_many_cubes(elf_bag).is_none()
+    }
+
+    /// The first cubes shown that the elf's bag doesn't have enough of.
+    fn too_many_cubes(&self, elf_bag: &HashMap<String, u32>) -> Option<&Cubes> {
+        self.cubes()
+            .find(|cubes| !is_bag_big_enough(elf_bag, &cubes.color, cubes.amount))
+    }
+
+    fn minimum_bag(&self) -> HashMap<String, u32> {
+        let mut minimum_game_bag = HashMap::new();
+        for cubes in self.cubes() {
+            if !is_bag_big_enough(&minimum_game_bag, &cubes.color, cubes.amount) {
+                minimum_game_bag.insert(cubes.color.clone(), cubes.amount);
+            }
+        }
+        minimum_game_bag
+    }
+
+    /// The minimum bag, such as `4 blue, 2 green, 6 red`.
+    fn format_minimum_bag(&self) -> String {
+        let mut bag: Vec<(String, u32)> = self.minimum_bag().into_iter().collect();
+        bag.sort();
+        let bag: Vec<String> = bag
+            .iter()
+            .map(|(color, amount)| format!("{amount} {color}"))
+            .collect();
+        bag.join(", ")
+    }
+
+    fn power(&self) -> Result<Uint, Overflow> {
+        let bag = self.minimum_bag();
+        numeric::product(bag.values().map(|amount| Uint::from(*amount)), |_| {
+            format!("the power of game {}", self.id)
+        })
+    }
+}
+
+pub struct GameRecord {
+    games: Vec<Game>,
+}
+
+impl Puzzle for GameRecord {
+    type Input = Self;
+    type Output = Uint;
+    const QUERIES: &'static [Query] = &[Query {
+        usage: "game <id>",
+        help: "whether game id is possible, and its minimum bag",
+    }];
+
+    #[instrument(skip_all)]
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let games: Vec<Game> = lines(input)
+            .map(Game::from_line)
+            .collect::<Result<_, _>>()?;
+        debug!(games = games.len(), "parsed the record");
+        Ok(GameRecord { games })
+    }
+
+    /// The sum of the IDs of the games that are possible with the elf's bag.
+    #[instrument(skip_all)]
+    fn part1(record: &Self) -> Result<Uint, SolveError> {
+        let elf_bag = elf_bag();
+        let mut sum = 0;
+        for game in &record.games {
+            let possible = game.is_possible(&elf_bag);
+            trace!(game = game.id, possible);
+            if possible {
+                sum = numeric::add(sum, game.id.into(), || format!("game {}", game.id))?;
+            }
+        }
+        Ok(sum)
+    }
+
+    /// The sum of the powers of the minimum bag of every game.
+    #[instrument(skip_all)]
+    fn part2(record: &Self) -> Result<Uint, SolveError> {
+        let powers = record
+            .games
+            .iter()
+            .map(|game| {
+                let power = game.power()?;
+                trace!(game = game.id, power);
+                Ok(power)
+            })
+            .collect::<Result<Vec<_>, Overflow>>()?;
+        Ok(numeric::sum(powers, |i| {
+            format!("game {}", record.games[i].id)
+        })?)
+    }
+
+    /// The ID of every possible game, or the power of every game.
+    fn explain(record: &Self, part: Part) -> Result<Option<Explanation>, SolveError> {
+        let elf_bag = elf_bag();
+        let contributions = record
+            .games
+            .iter()
+            .map(|game| {
+                let item = format!("Game {}", game.id);
+                match part {
+                    Part::One => match game.too_many_cubes(&elf_bag) {
+                        Some(cubes) => Contribution::new(
+                            item,
+                            format!("impossible, shows {} {}", cubes.amount, cubes.color),
+                            0,
+                        ),
+                        None => Contribution::new(item, "possible", game.id),
+                    },
+                    Part::Two => Contribution::new(item, game.format_minimum_bag(), game.power()?),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Some(Explanation::new(Combine::Sum, contributions)))
+    }
+
+    fn query(record: &Self, words: &[&str]) -> Option<Reply> {
+        let ["game", id] = words else {
+            return None;
+        };
+        let reply = query::number::<u32>(id).and_then(|id| {
+            let game = record
+                .games
+                .iter()
+                .find(|game| game.id == id)
+                .ok_or_else(|| format!("there's no game {id}"))?;
+            let possible = match game.too_many_cubes(&elf_bag()) {
+                Some(cubes) => format!("impossible, shows {} {}", cubes.amount, cubes.color),
+                None => "possible".to_string(),
+            };
+            let power = game
+                .power()
+                .map_err(|overflow| SolveError::from(overflow).to_string())?;
+            Ok(format!(
+                "Game {id} is {possible}, needs {} (power {power})",
+                game.format_minimum_bag(),
+            ))
+        });
+        Some(reply)
+    }
+}
+
+/// The cubes the elf has in the bag.
+fn elf_bag() -> HashMap<String, u32> {
+    HashMap::from([
+        (String::from("red"), 12),
+        (String::from("green"), 13),
+        (String::from("blue"), 14),
+    ])
+}
+
+fn is_bag_big_enough(bag: &HashMap<String, u32>, color: &str, amount: u32) -> bool {
+    bag.contains_key(color) && bag[color] >= amount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::assert_answers, explain::assert_explained};
+
+    #[test]
+    fn test_example_1() {
+        assert_answers::<GameRecord>(DATA_DIR, "example.txt");
+    }
+
+    #[test]
+    fn test_day_1() {
+        assert_answers::<GameRecord>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_explained::<GameRecord>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_query() {
+        let record = GameRecord::parse(
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        )
+        .unwrap();
+        assert_eq!(
+            GameRecord::query(&record, &["game", "3"]),
+            Some(Ok(
+                "Game 3 is impossible, shows 20 red, needs 6 blue, 13 green, 20 red (power 1560)"
+                    .to_string()
+            ))
+        );
+        assert!(GameRecord::query(&record, &["game", "4"]).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = GameRecord::parse("Game 1: 3 blue, 4 red\nGame 2: 1 blue, two green")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 17: invalid number `two`: invalid digit found in string"
+        );
+    }
+}
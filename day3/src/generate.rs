@@ -0,0 +1,50 @@
+use crate::Engine;
+use aoc_common::generate::{Generate, Rng};
+
+const SYMBOLS: &[u8] = b"*#+$/@%=-&";
+
+impl Generate for Engine {
+    /// An engine schematic of `size` by `size` cells, scattered with part
+    /// numbers of up to three digits and symbols, a few of them gears.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::new();
+        for _ in 0..size {
+            let mut row = vec![b'.'; size];
+            let mut col = 0;
+            while col < size {
+                if rng.chance(0.15) {
+                    let number = rng.range(1..1000).to_string();
+                    let end = (col + number.len()).min(size);
+                    row[col..end].copy_from_slice(&number.as_bytes()[..end - col]);
+                    // Keep numbers apart, so they aren't read as one
+                    col = end + 1;
+                } else {
+                    if rng.chance(0.05) {
+                        row[col] = *rng.choose(SYMBOLS);
+                    }
+                    col += 1;
+                }
+            }
+            input.push_str(std::str::from_utf8(&row).unwrap());
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        let input = Engine::generate(140, 3);
+        assert_eq!(input, Engine::generate(140, 3));
+        assert!(input.lines().all(|line| line.len() == 140));
+        let engine = Engine::parse(&input).unwrap();
+        assert!(Engine::part1(&engine).unwrap() > 0);
+        assert!(Engine::part2(&engine).unwrap() > 0);
+    }
+}
@@ -0,0 +1,350 @@
+mod generate;
+
+use aoc_common::{
+    explain::{Combine, Contribution, Explanation},
+    grid::{Grid, Position},
+    input::Span,
+    numeric::{self, Uint},
+    query::{self, Query, Reply},
+    ParseError, Part, Puzzle, SolveError,
+};
+use tracing::{debug, instrument, trace};
+
+/// Directory holding this day's example and puzzle inputs.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+struct PartNumber {
+    number: u32,
+    /// The position of the first digit.
+    start: Position,
+    len: usize,
+}
+
+impl PartNumber {
+    fn from_row(row: &[char], row_pos: usize, start: usize) -> Result<Self, ParseError> {
+        // Go until the end or until the first-found non-digit looking forwards
+        let mut end = row.len();
+        if let Some(right_dot_pos) = &row[start..].iter().position(|c| !c.is_ascii_digit()) {
+            end = start + right_dot_pos;
+        }
+        // Parse the part number
+        let digits = row[start..end].iter().collect::<String>();
+        let part_number = Span::new(&digits, row_pos + 1, start + 1).parse::<u32>()?;
+        Ok(PartNumber {
+            number: part_number,
+            start: Position::new(row_pos, start),
+            len: end - start,
+        })
+    }
+
+    /// The positions of the digits.
+    fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.len).map(|i| Position::new(self.start.row, self.start.col + i))
+    }
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+pub struct Engine {
+    grid: Grid<char>,
+    part_numbers: Vec<PartNumber>,
+    /// The index of the part number each cell is a digit of, if any.
+    digits: Grid<Option<usize>>,
+    symbols: Vec<Position>,
+}
+
+impl Engine {
+    fn from_string(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(input, "a character", Some)?;
+        let mut part_numbers = vec![];
+        let mut digits = Grid::new(grid.width(), grid.height(), None);
+        for i in 0..grid.height() {
+            let row = grid.row(i);
+            let mut j = 0;
+            while j < row.len() {
+                if row[j].is_ascii_digit() {
+                    let part_number = PartNumber::from_row(row, i, j)?;
+                    for position in part_number.positions() {
+                        digits[position] = Some(part_numbers.len());
+                    }
+                    j += part_number.len;
+                    part_numbers.push(part_number);
+                } else {
+                    j += 1;
+                }
+            }
+        }
+        let symbols: Vec<Position> = grid.find_all(|c| is_symbol(*c)).collect();
+        debug!(
+            part_numbers = part_numbers.len(),
+            symbols = symbols.len(),
+            "parsed the engine"
+        );
+        Ok(Self {
+            grid,
+            part_numbers,
+            digits,
+            symbols,
+        })
+    }
+
+    fn valid_part_numbers(&self) -> Vec<&PartNumber> {
+        self.part_numbers
+            .iter()
+            .filter(|p| {
+                let symbol = self.adjacent_symbol(p);
+                if let Some(symbol) = symbol {
+                    trace!(
+                        number = p.number,
+                        row = p.start.row,
+                        symbol = %self.grid[symbol],
+                        symbol_row = symbol.row,
+                        symbol_col = symbol.col,
+                        "part number"
+                    );
+                }
+                symbol.is_some()
+            })
+            .collect()
+    }
+
+    /// The first symbol next to the part number, row by row, if any.
+    fn adjacent_symbol(&self, part_number: &PartNumber) -> Option<Position> {
+        part_number
+            .positions()
+            .flat_map(|position| self.grid.neighbours8(position))
+            .filter(|&neighbour| is_symbol(self.grid[neighbour]))
+            .min()
+    }
+
+    fn adjacent_part_numbers(&self, symbol: Position) -> Vec<&PartNumber> {
+        let mut indexes: Vec<usize> = self
+            .grid
+            .neighbours8(symbol)
+            .filter_map(|neighbour| self.digits[neighbour])
+            .collect();
+        indexes.sort_unstable();
+        indexes.dedup();
+        indexes.iter().map(|&i| &self.part_numbers[i]).collect()
+    }
+
+    /// What's at `position`, as a reply to `at`.
+    fn describe(&self, position: Position) -> String {
+        let Some(&c) = self.grid.get(position) else {
+            return format!("nothing at {position}");
+        };
+        if is_symbol(c) {
+            let numbers: Vec<u32> = self
+                .adjacent_part_numbers(position)
+                .iter()
+                .map(|p| p.number)
+                .collect();
+            let item = format!("`{c}` at {position}");
+            return match numbers[..] {
+                [] => format!("{item} isn't next to any part number"),
+                [a, b] if c == '*' => format!(
+                    "{item} is a gear of {a} and {b}, ratio {}",
+                    gear_ratio(a, b)
+                ),
+                _ => {
+                    let numbers: Vec<String> = numbers.iter().map(u32::to_string).collect();
+                    format!("{item} is next to {}", numbers.join(", "))
+                }
+            };
+        }
+        if let Some(i) = self.digits[position] {
+            let p = &self.part_numbers[i];
+            let item = format!("{} at {}", p.number, p.start);
+            return match self.adjacent_symbol(p) {
+                Some(s) => format!("{item} is next to `{}` at {s}", self.grid[s]),
+                None => format!("{item} isn't next to any symbol"),
+            };
+        }
+        format!("nothing at {position}")
+    }
+
+    /// Every gear and its ratio.
+    fn gear_ratios(&self) -> Vec<(Position, Uint)> {
+        let mut ratios = vec![];
+        for &symbol in &self.symbols {
+            if self.grid[symbol] == '*' {
+                let adjacent = self.adjacent_part_numbers(symbol);
+                trace!(
+                    row = symbol.row,
+                    col = symbol.col,
+                    adjacent = adjacent.len(),
+                    "gear candidate"
+                );
+                if adjacent.len() == 2 {
+                    ratios.push((symbol, gear_ratio(adjacent[0].number, adjacent[1].number)));
+                }
+            }
+        }
+        ratios
+    }
+}
+
+fn gear_ratio(a: u32, b: u32) -> Uint {
+    // Two u32 always fit in a u64
+    Uint::from(a) * Uint::from(b)
+}
+
+impl Puzzle for Engine {
+    type Input = Self;
+    type Output = Uint;
+    const QUERIES: &'static [Query] = &[Query {
+        usage: "at <row> <col>",
+        help: "the part number or symbol at a position, and what it's next to",
+    }];
+
+    #[instrument(skip_all)]
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Engine::from_string(input)
+    }
+
+    /// The sum of the part numbers.
+    #[instrument(skip_all)]
+    fn part1(engine: &Self) -> Result<Uint, SolveError> {
+        let part_numbers = engine.valid_part_numbers();
+        Ok(numeric::sum(
+            part_numbers.iter().map(|p| p.number.into()),
+            |i| {
+                let p = part_numbers[i];
+                format!("{} at {}", p.number, p.start)
+            },
+        )?)
+    }
+
+    /// The sum of the gear ratios.
+    #[instrument(skip_all)]
+    fn part2(engine: &Self) -> Result<Uint, SolveError> {
+        let ratios = engine.gear_ratios();
+        Ok(numeric::sum(ratios.iter().map(|(_, ratio)| *ratio), |i| {
+            let (gear, _) = ratios[i];
+            format!("the gear at {gear}")
+        })?)
+    }
+
+    /// Every part number and the symbol it's next to, or every `*` and the
+    /// part numbers next to it.
+    fn explain(engine: &Self, part: Part) -> Result<Option<Explanation>, SolveError> {
+        let contributions = match part {
+            Part::One => engine
+                .part_numbers
+                .iter()
+                .map(|p| {
+                    let item = format!("{} at {}", p.number, p.start);
+                    match engine.adjacent_symbol(p) {
+                        Some(s) => Contribution::new(
+                            item,
+                            format!("next to `{}` at {s}", engine.grid[s]),
+                            p.number,
+                        ),
+                        None => Contribution::new(item, "no symbol nearby", 0),
+                    }
+                })
+                .collect::<Result<_, _>>()?,
+            Part::Two => engine
+                .symbols
+                .iter()
+                .filter(|&&s| engine.grid[s] == '*')
+                .map(|&s| {
+                    let item = format!("`*` at {s}");
+                    let numbers: Vec<u32> = engine
+                        .adjacent_part_numbers(s)
+                        .iter()
+                        .map(|p| p.number)
+                        .collect();
+                    match numbers[..] {
+                        [a, b] => Contribution::new(
+                            item,
+                            format!("gear of {a} and {b}"),
+                            gear_ratio(a, b),
+                        ),
+                        _ => Contribution::new(
+                            item,
+                            format!("next to {} part numbers", numbers.len()),
+                            0,
+                        ),
+                    }
+                })
+                .collect::<Result<_, _>>()?,
+        };
+        Ok(Some(Explanation::new(Combine::Sum, contributions)))
+    }
+
+    fn query(engine: &Self, words: &[&str]) -> Option<Reply> {
+        let ["at", row, col] = words else {
+            return None;
+        };
+        let reply = query::number::<usize>(row).and_then(|row| {
+            let col = query::number::<usize>(col)?;
+            if row == 0 || col == 0 {
+                return Err("rows and columns count from 1".to_string());
+            }
+            Ok(engine.describe(Position::new(row - 1, col - 1)))
+        });
+        Some(reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::assert_answers, explain::assert_explained};
+
+    #[test]
+    fn test_example() {
+        assert_answers::<Engine>(DATA_DIR, "example.txt");
+    }
+
+    #[test]
+    fn test_day_1() {
+        assert_answers::<Engine>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_explained::<Engine>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_query() {
+        let engine = Engine::parse_file(format!("{DATA_DIR}/example.txt")).unwrap();
+        let at = |row: &str, col: &str| Engine::query(&engine, &["at", row, col]).unwrap();
+        assert_eq!(
+            at("2", "4"),
+            Ok("`*` at row 2, column 4 is a gear of 467 and 35, ratio 16345".to_string())
+        );
+        assert_eq!(
+            at("1", "7"),
+            Ok("114 at row 1, column 6 isn't next to any symbol".to_string())
+        );
+        assert_eq!(
+            at("3", "3"),
+            Ok("35 at row 3, column 3 is next to `*` at row 2, column 4".to_string())
+        );
+        assert_eq!(at("1", "4"), Ok("nothing at row 1, column 4".to_string()));
+        assert!(at("0", "4").is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Engine::parse("467..114.......\n...*...........\n..99999999999..")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 3: invalid number `99999999999`: number too large to fit in target type"
+        );
+        let error = Engine::parse("467..114..\n...*......\n..35..")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected a row of 10 cells, found `..35..`"
+        );
+    }
+}
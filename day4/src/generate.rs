@@ -0,0 +1,58 @@
+use crate::CardPile;
+use aoc_common::generate::{Generate, Rng};
+
+const WINNING: usize = 10;
+const NUMBERS: usize = 25;
+
+impl Generate for CardPile {
+    /// A pile of `size` scratchcards. Most cards win nothing, so that the
+    /// copies won in part 2 don't grow without bound.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::new();
+        for id in 1..=size {
+            let mut pool: Vec<u32> = (1..100).collect();
+            rng.shuffle(&mut pool);
+            let wins = if rng.chance(0.7) { 0 } else { rng.range(1..5) } as usize;
+            let winning = &pool[..WINNING];
+            // The numbers we have are the first `wins` winning numbers and
+            // numbers that don't win
+            let mut mine: Vec<u32> = pool[..wins]
+                .iter()
+                .chain(&pool[WINNING..(WINNING + NUMBERS - wins)])
+                .copied()
+                .collect();
+            rng.shuffle(&mut mine);
+            let format = |numbers: &[u32]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            input.push_str(&format!(
+                "Card {id:3}: {} | {}\n",
+                format(winning),
+                format(&mine)
+            ));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        let input = CardPile::generate(500, 4);
+        assert_eq!(input, CardPile::generate(500, 4));
+        let pile = CardPile::parse(&input).unwrap();
+        assert_eq!(pile.pile.len(), 500);
+        assert!(pile.pile.iter().all(|entry| entry.card.wins() <= 4));
+        assert!(CardPile::part1(&pile).unwrap() > 0);
+        assert!(CardPile::part2(&pile).unwrap() > 500);
+    }
+}
@@ -0,0 +1,300 @@
+mod generate;
+
+use aoc_common::{
+    explain::{Combine, Contribution, Explanation},
+    input::{lines, Span},
+    numeric::{self, Overflow, Uint},
+    query::{self, Query, Reply},
+    ParseError, Part, Puzzle, SolveError,
+};
+use tracing::{debug, instrument, trace};
+
+/// Directory holding this day's example and puzzle inputs.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+#[derive(Clone)]
+struct Card {
+    id: u32,
+    winning: Vec<u32>,
+    my_numbers: Vec<u32>,
+}
+
+impl Card {
+    fn from_line(line: Span) -> Result<Self, ParseError> {
+        let (name, nums) = line.split_once(':')?;
+        let id = name.strip_prefix("Card")?.trim().parse()?;
+        let (wins, mine) = nums.split_once('|')?;
+        let winning = wins.numbers()?;
+        let my_numbers = mine.numbers()?;
+        Ok(Card {
+            id,
+            winning,
+            my_numbers,
+        })
+    }
+
+    fn wins(&self) -> usize {
+        self.my_numbers
+            .iter()
+            .filter(|x| self.winning.contains(x))
+            .count()
+    }
+
+    fn points(&self) -> Result<Uint, Overflow> {
+        let wins = self.wins();
+        if wins > 0 {
+            numeric::pow(2, wins as u32 - 1, || {
+                format!("the points of card {} with {wins} wins", self.id)
+            })
+        } else {
+            Ok(0)
+        }
+    }
+}
+
+#[derive(Clone)]
+struct CardPileEntry {
+    card: Card,
+    copies: Uint,
+}
+
+#[derive(Clone)]
+pub struct CardPile {
+    pile: Vec<CardPileEntry>,
+}
+
+impl CardPile {
+    fn from_cards(cards: Vec<Card>) -> Self {
+        let pile = cards
+            .into_iter()
+            .map(|c| CardPileEntry { card: c, copies: 1 })
+            .collect();
+        CardPile { pile }
+    }
+
+    fn total_cards(&mut self) -> Result<Uint, Overflow> {
+        let pile = &mut self.pile;
+        let size = pile.len();
+        // Update card copies
+        for i in 0..size {
+            let entry = &pile[i];
+            let copies = entry.copies;
+            let wins = entry.card.wins();
+            trace!(card = i + 1, copies, wins, "won copies");
+            if wins > 0 {
+                for (j, e) in pile
+                    .iter_mut()
+                    .enumerate()
+                    .take((i + 1 + wins).min(size))
+                    .skip(i + 1)
+                {
+                    e.copies =
+                        numeric::add(e.copies, copies, || format!("copies of card {}", j + 1))?;
+                }
+            }
+        }
+        // Return the sum of copies
+        numeric::sum(pile.iter().map(|e| e.copies), |i| format!("card {}", i + 1))
+    }
+}
+
+impl Puzzle for CardPile {
+    type Input = Self;
+    type Output = Uint;
+    const QUERIES: &'static [Query] = &[Query {
+        usage: "card <n>",
+        help: "the winning numbers and points of card n, and how many of it are won",
+    }];
+
+    #[instrument(skip_all)]
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let cards: Vec<Card> = lines(input)
+            .map(Card::from_line)
+            .collect::<Result<_, _>>()?;
+        debug!(cards = cards.len(), "parsed the pile");
+        Ok(CardPile::from_cards(cards))
+    }
+
+    /// The sum of the points of all cards.
+    #[instrument(skip_all)]
+    fn part1(pile: &Self) -> Result<Uint, SolveError> {
+        let points = pile
+            .pile
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let points = e.card.points()?;
+                trace!(card = i + 1, points);
+                Ok(points)
+            })
+            .collect::<Result<Vec<_>, Overflow>>()?;
+        Ok(numeric::sum(points, |i| format!("card {}", i + 1))?)
+    }
+
+    /// The total amount of cards.
+    #[instrument(skip_all)]
+    fn part2(pile: &Self) -> Result<Uint, SolveError> {
+        // Counting the cards updates the copies, so work on a fresh pile
+        Ok(pile.clone().total_cards()?)
+    }
+
+    /// The points of every card, or how many of every card there are.
+    fn explain(pile: &Self, part: Part) -> Result<Option<Explanation>, SolveError> {
+        let item = |i: usize| format!("Card {}", i + 1);
+        let contributions = match part {
+            Part::One => pile
+                .pile
+                .iter()
+                .enumerate()
+                .map(|(i, e)| {
+                    let wins = e.card.wins();
+                    Contribution::new(item(i), format!("{wins} winning numbers"), e.card.points()?)
+                })
+                .collect::<Result<_, _>>()?,
+            Part::Two => {
+                let mut counted = pile.clone();
+                counted.total_cards()?;
+                counted
+                    .pile
+                    .iter()
+                    .enumerate()
+                    .map(|(i, e)| {
+                        let detail = format!(
+                            "1 original and {} won copies, each winning {} cards",
+                            e.copies - 1,
+                            e.card.wins()
+                        );
+                        Contribution::new(item(i), detail, e.copies)
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+        };
+        Ok(Some(Explanation::new(Combine::Sum, contributions)))
+    }
+
+    fn query(pile: &Self, words: &[&str]) -> Option<Reply> {
+        let ["card", n] = words else {
+            return None;
+        };
+        let overflowed = |overflow| SolveError::from(overflow).to_string();
+        let reply = query::nth(&pile.pile, n, "card").and_then(|e| {
+            let points = e.card.points().map_err(overflowed)?;
+            let winning: Vec<String> = e
+                .card
+                .my_numbers
+                .iter()
+                .filter(|x| e.card.winning.contains(x))
+                .map(u32::to_string)
+                .collect();
+            // The points don't depend on how many cards are won
+            let mut counted = pile.clone();
+            let cards = match counted.total_cards() {
+                Ok(_) => query::nth(&counted.pile, n, "card")?.copies.to_string(),
+                Err(overflow) => overflowed(overflow),
+            };
+            Ok(format!(
+                "Card {n}: {} winning numbers ({}), {points} points, {cards} cards in the end",
+                winning.len(),
+                winning.join(", "),
+            ))
+        });
+        Some(reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::assert_answers, explain::assert_explained, generate::Rng};
+
+    #[test]
+    fn test_example() {
+        assert_answers::<CardPile>(DATA_DIR, "example.txt");
+    }
+
+    #[test]
+    fn test_input() {
+        assert_answers::<CardPile>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_explained::<CardPile>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_query() {
+        let pile = CardPile::parse_file(format!("{DATA_DIR}/example.txt")).unwrap();
+        assert_eq!(
+            CardPile::query(&pile, &["card", "3"]),
+            Some(Ok(
+                "Card 3: 2 winning numbers (21, 1), 2 points, 4 cards in the end".to_string()
+            ))
+        );
+        assert!(CardPile::query(&pile, &["card", "7"]).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_explain_overflowing_copies() {
+        // Every card wins copies of the next ten, which double for each card
+        let input: String = (1..=200)
+            .map(|id| format!("Card {id}: 1 2 3 4 5 6 7 8 9 10 | 1 2 3 4 5 6 7 8 9 10\n"))
+            .collect();
+        let pile = CardPile::parse(&input).unwrap();
+        let explanation = CardPile::explain(&pile, Part::One).unwrap().unwrap();
+        assert_eq!(explanation.total(), Ok(Some(200 * 512)));
+        assert!(CardPile::explain(&pile, Part::Two).is_err());
+        let reply = CardPile::query(&pile, &["card", "3"]).unwrap().unwrap();
+        assert!(
+            reply.starts_with("Card 3: 10 winning numbers (1, 2, 3, 4, 5, 6, 7, 8, 9, 10), 512 points, overflow at copies of card"),
+            "{reply}"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = CardPile::parse("Card 1: 41 48 83 86 17 83 86  6 31 17  9 48 53")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 8: expected `|`, found ` 41 48 83 86 17 83 86  6 31 17  9 48 53`"
+        );
+    }
+
+    #[test]
+    fn test_points_double_per_win() {
+        let mut rng = Rng::new(4);
+        for _ in 0..1000 {
+            // Deal distinct numbers, so exactly `wins` of mine are winning
+            let mut numbers: Vec<u32> = (1..100).collect();
+            rng.shuffle(&mut numbers);
+            let wins = rng.below(11) as usize;
+            let winning = numbers[..10].to_vec();
+            let mut my_numbers = numbers[..wins].to_vec();
+            my_numbers.extend_from_slice(&numbers[10..(35 - wins)]);
+            rng.shuffle(&mut my_numbers);
+            let card = Card {
+                id: 1,
+                winning,
+                my_numbers,
+            };
+            assert_eq!(card.wins(), wins);
+            let expected = if wins == 0 { 0 } else { 1 << (wins - 1) };
+            assert_eq!(card.points(), Ok(expected), "{wins} wins");
+        }
+    }
+
+    #[test]
+    fn test_points_overflow() {
+        let card = Card {
+            id: 7,
+            winning: (0..200).collect(),
+            my_numbers: (0..200).collect(),
+        };
+        assert_eq!(
+            card.points().unwrap_err().at,
+            "the points of card 7 with 200 wins"
+        );
+    }
+}
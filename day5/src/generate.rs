@@ -0,0 +1,77 @@
+use crate::Almanac;
+use aoc_common::generate::{Generate, Rng};
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// The numbers the maps shuffle around, like in the real inputs.
+const UNIVERSE: u64 = 1 << 32;
+
+const SEED_RANGES: usize = 10;
+const MAX_SEED_RANGE: u64 = 100_000;
+
+impl Generate for Almanac {
+    /// An almanac whose maps have `size` entries each, together moving every
+    /// number below 2^32, and ten seed ranges. Seed ranges stay short enough
+    /// for part 2 to search them.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let seeds: Vec<String> = (0..SEED_RANGES)
+            .map(|_| {
+                let length = rng.range(1..MAX_SEED_RANGE);
+                format!("{} {}", rng.below(UNIVERSE - length), length)
+            })
+            .collect();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+        for name in MAPS {
+            input.push_str(&format!("\n{name} map:\n"));
+            for (destination, source, length) in map_entries(&mut rng, size.max(1)) {
+                input.push_str(&format!("{destination} {source} {length}\n"));
+            }
+        }
+        input
+    }
+}
+
+/// The `(destination, source, length)` of `size` entries that move the
+/// pieces of the universe around.
+fn map_entries(rng: &mut Rng, size: usize) -> Vec<(u64, u64, u64)> {
+    let mut cuts: Vec<u64> = (1..size).map(|_| rng.range(1..UNIVERSE)).collect();
+    cuts.extend([0, UNIVERSE]);
+    cuts.sort_unstable();
+    cuts.dedup();
+    let mut sources: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+    rng.shuffle(&mut sources);
+    let mut destination = 0;
+    sources
+        .into_iter()
+        .map(|(source, length)| {
+            let entry = (destination, source, length);
+            destination += length;
+            entry
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        let input = Almanac::generate(20, 5);
+        assert_eq!(input, Almanac::generate(20, 5));
+        let almanac = Almanac::parse(&input).unwrap();
+        assert_eq!(almanac.category_maps.len(), 7);
+        let lowest = Almanac::part1(&almanac).unwrap();
+        assert!(Almanac::part2(&almanac).unwrap() <= lowest);
+    }
+}
@@ -0,0 +1,443 @@
+mod generate;
+#[cfg(test)]
+mod reference;
+
+use aoc_common::{
+    error::Location,
+    explain::{Combine, Contribution, Explanation},
+    input::{sections, Span},
+    query::{self, Query, Reply},
+    range_set::RangeSet,
+    ParseError, Part, Puzzle, SolveError,
+};
+use std::{cmp::Ordering, iter::Iterator, ops::Range};
+use tracing::{debug, instrument, trace};
+
+/// Directory holding this day's example and puzzle inputs.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+struct CategoryMapEntry {
+    destination_range: Range<u64>,
+    source_range: Range<u64>,
+}
+
+impl CategoryMapEntry {
+    fn is_number_in_range(&self, num: u64) -> bool {
+        self.source_range.contains(&num)
+    }
+
+    fn get_number_destination(&self, num: u64) -> u64 {
+        self.destination_range.start + num - self.source_range.start
+    }
+
+    fn is_before_entry(&self, entry: &CategoryMapEntry) -> Ordering {
+        if self.source_range.start < entry.source_range.start {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+struct CategoryMap {
+    entries: Vec<CategoryMapEntry>,
+}
+
+impl CategoryMap {
+    fn find_number_destination(&self, num: u64) -> u64 {
+        if let Some(entry) = self.entries.iter().find(|e| e.is_number_in_range(num)) {
+            entry.get_number_destination(num)
+        } else {
+            num
+        }
+    }
+
+    /// Where this filled map sends the numbers of `numbers`.
+    fn destinations(&self, numbers: &RangeSet<u64>) -> RangeSet<u64> {
+        let mut destinations = RangeSet::new();
+        for entry in &self.entries {
+            let sources = numbers.intersection(&entry.source_range.clone().into());
+            let moved = sources.shift(entry.source_range.start, entry.destination_range.start);
+            destinations = destinations.union(&moved);
+        }
+        destinations
+    }
+
+    /// The numbers this filled map sends into `numbers`.
+    fn sources(&self, numbers: &RangeSet<u64>) -> RangeSet<u64> {
+        let mut sources = RangeSet::new();
+        for entry in &self.entries {
+            let destinations = numbers.intersection(&entry.destination_range.clone().into());
+            let moved = destinations.shift(entry.destination_range.start, entry.source_range.start);
+            sources = sources.union(&moved);
+        }
+        sources
+    }
+
+    fn fill_category_map(&mut self) {
+        // Sort reversed, because pop() will grab from the end
+        self.entries.sort_unstable_by(|a, b| b.is_before_entry(a));
+        let mut final_entries = vec![];
+        let mut start = 0;
+        while let Some(entry) = self.entries.pop() {
+            let entry_start = entry.source_range.start;
+            if start < entry_start {
+                final_entries.push(CategoryMapEntry {
+                    destination_range: start..entry_start,
+                    source_range: start..entry_start,
+                });
+            }
+            start = entry.source_range.end;
+            final_entries.push(entry);
+        }
+        final_entries.push(CategoryMapEntry {
+            destination_range: start..u64::MAX,
+            source_range: start..u64::MAX,
+        });
+        self.entries = final_entries;
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    category_maps: Vec<CategoryMap>,
+    seed_ranges: Vec<Range<u64>>,
+}
+
+impl Almanac {
+    fn from_string(input: &str) -> Result<Self, ParseError> {
+        let sections = sections(input);
+        let Some(seeds_section) = sections.first() else {
+            return Err(ParseError::UnexpectedEnd {
+                location: Location::new(1, 1),
+                expected: "the seeds".to_string(),
+            });
+        };
+        let (seeds, seed_ranges) = Self::parse_seeds(seeds_section[0])?;
+        let mut category_maps: Vec<CategoryMap> = sections[1..]
+            .iter()
+            // Skip the map title
+            .map(|section| Self::parse_category_map(&section[1..]))
+            .collect::<Result<_, _>>()?;
+        if category_maps.is_empty() {
+            let last_line = seeds_section[seeds_section.len() - 1];
+            return Err(last_line.end_error("a category map"));
+        }
+        Self::fill_category_maps(&mut category_maps);
+        debug!(
+            seeds = seeds.len(),
+            maps = category_maps.len(),
+            entries = category_maps.iter().map(|m| m.entries.len()).sum::<usize>(),
+            "parsed the almanac"
+        );
+        Ok(Almanac {
+            seeds,
+            category_maps,
+            seed_ranges,
+        })
+    }
+
+    /// The seeds of `line`, and the seed ranges part 2 reads them as.
+    fn parse_seeds(line: Span) -> Result<(Vec<u64>, Vec<Range<u64>>), ParseError> {
+        let nums = line.split_once(':')?.1.trim();
+        let fields: Vec<Span> = nums.split_whitespace().collect();
+        let seeds: Vec<u64> = nums.numbers()?;
+        if seeds.is_empty() {
+            return Err(line.end_error("a seed"));
+        }
+        // Part 1 looks at the range of every seed on its own
+        for (field, seed) in fields.iter().zip(&seeds) {
+            seed.checked_add(1).ok_or_else(|| field.too_large_error())?;
+        }
+        // Part 2 reads the seeds as pairs of range start and length
+        if !seeds.len().is_multiple_of(2) {
+            return Err(nums.error("an even amount of seeds"));
+        }
+        let seed_ranges = fields
+            .chunks(2)
+            .zip(seeds.chunks(2))
+            .map(|(fields, s)| {
+                let end = s[0]
+                    .checked_add(s[1])
+                    .ok_or_else(|| fields[1].too_large_error())?;
+                Ok(s[0]..end)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((seeds, seed_ranges))
+    }
+
+    fn parse_category_map(entries: &[Span]) -> Result<CategoryMap, ParseError> {
+        let map_entries = entries
+            .iter()
+            .map(|e| {
+                let parsed: Vec<u64> = e.numbers()?;
+                if parsed.len() != 3 {
+                    return Err(e.error("a destination, a source and a range length"));
+                }
+                let destination = parsed[0];
+                let source = parsed[1];
+                let range = parsed[2];
+                // The length is the last number, and the one that overflows
+                let too_large = || e.split_whitespace().last().unwrap_or(*e).too_large_error();
+                Ok(CategoryMapEntry {
+                    destination_range: destination
+                        ..destination.checked_add(range).ok_or_else(too_large)?,
+                    source_range: source..source.checked_add(range).ok_or_else(too_large)?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(CategoryMap {
+            entries: map_entries,
+        })
+    }
+
+    fn fill_category_maps(maps: &mut [CategoryMap]) {
+        maps.iter_mut().for_each(|map| map.fill_category_map());
+    }
+
+    /// The numbers `seed` is mapped to by every map, ending with its location.
+    fn find_path(&self, seed: u64) -> Vec<u64> {
+        let mut path = vec![seed];
+        for map in &self.category_maps {
+            path.push(map.find_number_destination(path[path.len() - 1]));
+        }
+        path
+    }
+
+    fn find_lowest_location_from_seeds(&self) -> Option<u64> {
+        let seeds = self.seeds.iter().map(|seed| *seed..(seed + 1)).collect();
+        self.find_lowest_seed_in_ranges(&seeds)
+            .map(|(_, location)| location)
+    }
+
+    fn find_lowest_location_from_seed_ranges(&self) -> Option<u64> {
+        let seeds = self.seed_ranges.iter().cloned().collect();
+        self.find_lowest_seed_in_ranges(&seeds)
+            .map(|(_, location)| location)
+    }
+
+    /// The seed of `seeds` with the lowest location, and its location.
+    fn find_lowest_seed_in_ranges(&self, seeds: &RangeSet<u64>) -> Option<(u64, u64)> {
+        // Send every range of numbers through the maps at once
+        let mut numbers = seeds.clone();
+        for (hop, map) in self.category_maps.iter().enumerate() {
+            numbers = map.destinations(&numbers);
+            trace!(hop, ranges = numbers.iter().len(), "mapped the seed ranges");
+        }
+        let location = numbers.min()?;
+        // Then bring the lowest location back to the seeds it comes from
+        let mut numbers = RangeSet::from(location..(location + 1));
+        for map in self.category_maps.iter().rev() {
+            numbers = map.sources(&numbers);
+        }
+        let seed = numbers.intersection(seeds).min()?;
+        debug!(seed, location, "found the lowest location");
+        Some((seed, location))
+    }
+}
+
+impl Puzzle for Almanac {
+    type Input = Self;
+    type Output = u64;
+    // The seed ranges used to be found by mapping location ranges back
+    const VERSION: u32 = 2;
+    const QUERIES: &'static [Query] = &[Query {
+        usage: "location <seed>",
+        help: "the numbers any seed is mapped to, ending with its location",
+    }];
+
+    #[instrument(skip_all)]
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Almanac::from_string(input)
+    }
+
+    /// The lowest location of the seeds.
+    #[instrument(skip_all)]
+    fn part1(almanac: &Self) -> Result<u64, SolveError> {
+        almanac
+            .find_lowest_location_from_seeds()
+            .ok_or_else(|| SolveError::Unsolvable("no seed has a location".to_string()))
+    }
+
+    /// The lowest location of the seed ranges.
+    #[instrument(skip_all)]
+    fn part2(almanac: &Self) -> Result<u64, SolveError> {
+        almanac
+            .find_lowest_location_from_seed_ranges()
+            .ok_or_else(|| SolveError::Unsolvable("no seed range has a location".to_string()))
+    }
+
+    /// The location of every seed, or the lowest location of every seed range.
+    fn explain(almanac: &Self, part: Part) -> Result<Option<Explanation>, SolveError> {
+        let contributions = match part {
+            Part::One => almanac
+                .seeds
+                .iter()
+                .map(|seed| {
+                    let path = almanac.find_path(*seed);
+                    let hops: Vec<String> = path.iter().map(u64::to_string).collect();
+                    Contribution::new(
+                        format!("Seed {seed}"),
+                        hops.join(" -> "),
+                        path[path.len() - 1],
+                    )
+                })
+                .collect::<Result<_, _>>()?,
+            Part::Two => almanac
+                .seed_ranges
+                .iter()
+                .filter_map(|range| {
+                    let (seed, location) =
+                        almanac.find_lowest_seed_in_ranges(&range.clone().into())?;
+                    Some(Contribution::new(
+                        format!("Seeds {}..{}", range.start, range.end),
+                        format!("lowest at seed {seed}"),
+                        location,
+                    ))
+                })
+                .collect::<Result<_, _>>()?,
+        };
+        Ok(Some(Explanation::new(Combine::Minimum, contributions)))
+    }
+
+    fn query(almanac: &Self, words: &[&str]) -> Option<Reply> {
+        let ["location", seed] = words else {
+            return None;
+        };
+        Some(query::number::<u64>(seed).map(|seed| {
+            let path = almanac.find_path(seed);
+            let hops: Vec<String> = path.iter().map(u64::to_string).collect();
+            format!(
+                "Seed {seed}: {}, location {}",
+                hops.join(" -> "),
+                path[path.len() - 1]
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::assert_answers, explain::assert_explained, generate::Rng};
+
+    #[test]
+    fn test_example() {
+        assert_answers::<Almanac>(DATA_DIR, "example.txt");
+    }
+
+    #[test]
+    fn test_input() {
+        assert_answers::<Almanac>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_explained::<Almanac>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_query() {
+        let almanac = Almanac::parse_file(format!("{DATA_DIR}/example.txt")).unwrap();
+        assert_eq!(
+            Almanac::query(&almanac, &["location", "79"]),
+            Some(Ok(
+                "Seed 79: 79 -> 81 -> 81 -> 81 -> 74 -> 78 -> 78 -> 82, location 82".to_string()
+            ))
+        );
+        assert!(Almanac::query(&almanac, &["location", "-1"])
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Almanac::parse("seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n52 50")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 1: expected a destination, a source and a range length, found `52 50`"
+        );
+        let error = |input: &str| Almanac::parse(input).err().unwrap().to_string();
+        let maps = "\n\nseed-to-soil map:\n50 98 2";
+        assert_eq!(
+            error(&format!("seeds:{maps}")),
+            "line 1, column 7: expected a seed, found the end of the input"
+        );
+        assert_eq!(
+            error(&format!("seeds: 79 14 55{maps}")),
+            "line 1, column 8: expected an even amount of seeds, found `79 14 55`"
+        );
+        assert_eq!(
+            error(&format!("seeds: 79 18446744073709551600{maps}")),
+            "line 1, column 11: invalid number `18446744073709551600`: \
+             number too large to fit in target type"
+        );
+        assert_eq!(
+            error("seeds: 79 14\n\nseed-to-soil map:\n50 18446744073709551600 20"),
+            "line 4, column 25: invalid number `20`: number too large to fit in target type"
+        );
+    }
+
+    #[test]
+    fn test_fill_category_map_covers_all_numbers() {
+        let mut rng = Rng::new(5);
+        for _ in 0..1000 {
+            // Entries with disjoint sources, some of them back to back
+            let mut entries = vec![];
+            let mut end = 0;
+            for _ in 0..rng.below(8) {
+                let start = end
+                    + if rng.chance(0.3) {
+                        0
+                    } else {
+                        rng.range(1..1000)
+                    };
+                end = start + rng.range(1..1000);
+                let destination = rng.below(1 << 40);
+                entries.push((destination, start..end));
+            }
+            rng.shuffle(&mut entries);
+            let mut map = CategoryMap {
+                entries: entries
+                    .iter()
+                    .map(|(destination, source)| CategoryMapEntry {
+                        destination_range: *destination..(destination + source.end - source.start),
+                        source_range: source.clone(),
+                    })
+                    .collect(),
+            };
+            let numbers: Vec<u64> = (0..20).map(|_| rng.below(end + 10)).collect();
+            let destinations: Vec<u64> = numbers
+                .iter()
+                .map(|n| map.find_number_destination(*n))
+                .collect();
+
+            map.fill_category_map();
+
+            let sources: Vec<Range<u64>> =
+                map.entries.iter().map(|e| e.source_range.clone()).collect();
+            assert_eq!(sources[0].start, 0, "{sources:?}");
+            assert_eq!(sources[sources.len() - 1].end, u64::MAX, "{sources:?}");
+            assert!(
+                sources.windows(2).all(|pair| pair[0].end == pair[1].start),
+                "{sources:?}"
+            );
+            assert!(
+                sources.iter().all(|source| !source.is_empty()),
+                "{sources:?}"
+            );
+            for entry in &map.entries {
+                let original = entries
+                    .iter()
+                    .find(|(_, source)| *source == entry.source_range);
+                let destination = original.map_or(entry.source_range.start, |(d, _)| *d);
+                assert_eq!(entry.destination_range.start, destination);
+            }
+            for (number, destination) in numbers.iter().zip(destinations) {
+                assert_eq!(map.find_number_destination(*number), destination);
+            }
+        }
+    }
+}
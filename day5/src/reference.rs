@@ -0,0 +1,109 @@
+//! A brute-force reference for the lowest locations, which the solver finds
+//! by mapping the seed ranges forward through every map as range sets.
+
+use crate::Almanac;
+use aoc_common::{
+    generate::{Generate, Rng},
+    input::{parse_numbers, sections},
+    Puzzle,
+};
+
+/// The seeds and the `(destination, source, length)` entries of every map,
+/// read without any of the solver's code.
+fn read(input: &str) -> (Vec<u64>, Vec<Vec<[u64; 3]>>) {
+    let sections = sections(input);
+    let seeds = parse_numbers(sections[0][0].text.split_once(':').unwrap().1).unwrap();
+    let maps = sections[1..]
+        .iter()
+        .map(|section| {
+            section[1..]
+                .iter()
+                .map(|line| parse_numbers(line.text).unwrap().try_into().unwrap())
+                .collect()
+        })
+        .collect();
+    (seeds, maps)
+}
+
+fn location(maps: &[Vec<[u64; 3]>], seed: u64) -> u64 {
+    maps.iter().fold(seed, |number, entries| {
+        entries
+            .iter()
+            .find(|[_, source, length]| (*source..(source + length)).contains(&number))
+            .map_or(number, |[destination, source, _]| {
+                destination + number - source
+            })
+    })
+}
+
+/// Map every seed, or every seed of every seed range, to its location.
+fn lowest_location(input: &str, ranges: bool) -> u64 {
+    let (seeds, maps) = read(input);
+    let seeds: Vec<u64> = if ranges {
+        seeds
+            .chunks(2)
+            .flat_map(|pair| pair[0]..(pair[0] + pair[1]))
+            .collect()
+    } else {
+        seeds
+    };
+    seeds
+        .into_iter()
+        .map(|seed| location(&maps, seed))
+        .min()
+        .unwrap()
+}
+
+/// A small almanac whose maps, unlike generated ones, leave numbers out and
+/// send several ranges to overlapping destinations.
+fn random_almanac(rng: &mut Rng) -> String {
+    let seeds: Vec<String> = (0..rng.range(1..4))
+        .map(|_| format!("{} {}", rng.below(90), rng.range(1..10)))
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+    for map in 0..rng.range(1..5) {
+        input.push_str(&format!("\nmap {map}:\n"));
+        let mut start = rng.below(10);
+        for _ in 0..rng.range(1..5) {
+            let length = rng.range(1..20);
+            input.push_str(&format!("{} {} {}\n", rng.below(100), start, length));
+            start += length + rng.below(10);
+        }
+    }
+    input
+}
+
+#[test]
+fn test_generated_almanacs() {
+    for seed in 0..3 {
+        let input = Almanac::generate(20, seed);
+        let almanac = Almanac::parse(&input).unwrap();
+        assert_eq!(
+            Almanac::part1(&almanac).unwrap(),
+            lowest_location(&input, false)
+        );
+        assert_eq!(
+            Almanac::part2(&almanac).unwrap(),
+            lowest_location(&input, true)
+        );
+    }
+}
+
+#[test]
+fn test_random_almanacs() {
+    let mut rng = Rng::new(5);
+    for _ in 0..2_000 {
+        let input = random_almanac(&mut rng);
+        let almanac = Almanac::parse(&input).unwrap();
+        assert_eq!(
+            Almanac::part1(&almanac).unwrap(),
+            lowest_location(&input, false),
+            "{input}"
+        );
+        assert_eq!(
+            Almanac::part2(&almanac).unwrap(),
+            lowest_location(&input, true),
+            "{input}"
+        );
+    }
+}
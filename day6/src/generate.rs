@@ -0,0 +1,49 @@
+use crate::RaceSheet;
+use aoc_common::generate::{Generate, Rng};
+
+impl Generate for RaceSheet {
+    /// A sheet of up to four races whose single, long race lasts about
+    /// `size` milliseconds, which is what part 2's search takes time with.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let digits = size.max(1).to_string().len();
+        let races = digits.min(4);
+        let mut times = vec![];
+        let mut distances = vec![];
+        for race in 0..races {
+            // Share the digits of the long race between the races
+            let race_digits = digits / races + usize::from(race < digits % races);
+            let low = 10u64.pow(race_digits as u32 - 1);
+            let time = rng.range(low.max(5)..(low * 10));
+            // Like in the real inputs, the record is at least the race time.
+            // It stays below the distance reached by holding for half the
+            // race, and a digit shorter than its square, so that the long race
+            // can be won too.
+            let limit = (time * time / 4).min(10u64.pow(2 * race_digits as u32 - 1));
+            times.push(time);
+            distances.push(rng.range(time..limit.max(time + 1)));
+        }
+        let line = |title: &str, numbers: &[u64]| {
+            let numbers: Vec<String> = numbers.iter().map(|n| format!("{n:>6}")).collect();
+            format!("{title}{}\n", numbers.join(" "))
+        };
+        line("Time:    ", &times) + &line("Distance:", &distances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        let input = RaceSheet::generate(1_000_000, 6);
+        assert_eq!(input, RaceSheet::generate(1_000_000, 6));
+        let sheet = RaceSheet::parse(&input).unwrap();
+        assert_eq!(sheet.races.len(), 4);
+        assert_eq!(sheet.race.time.to_string().len(), 7);
+        assert!(RaceSheet::part1(&sheet).unwrap() > 0);
+        assert!(RaceSheet::part2(&sheet).unwrap() > 0);
+    }
+}
@@ -0,0 +1,240 @@
+mod generate;
+#[cfg(test)]
+mod reference;
+
+use aoc_common::{
+    explain::{Combine, Contribution, Explanation},
+    input::{lines, Span},
+    numeric::{self, Uint},
+    query::{self, Query, Reply},
+    ParseError, Part, Puzzle, SolveError,
+};
+use std::ops::Range;
+use tracing::{instrument, trace};
+
+/// Directory holding this day's example and puzzle inputs.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    fn is_time_held_new_record(&self, time_held: u64) -> bool {
+        if time_held > self.time {
+            return false;
+        }
+
+        let time_available = self.time - time_held;
+        let speed = time_held;
+        // A distance too far for a u64 is further than any record
+        time_available
+            .checked_mul(speed)
+            .is_none_or(|distance| distance > self.distance)
+    }
+
+    fn ways_to_beat_record(&self) -> u64 {
+        let holds = self.record_holds();
+        holds.end - holds.start
+    }
+
+    /// The times to hold the button for to beat the record.
+    fn record_holds(&self) -> Range<u64> {
+        // Holding for half the race goes the furthest, so if that doesn't
+        // beat the record nothing does
+        let middle = self.time / 2;
+        if !self.is_time_held_new_record(middle) {
+            return 0..0;
+        }
+
+        // Holding for no time or the whole race never moves the boat, so
+        // both searches stop by then
+        let left_pos = (0..middle)
+            .rev()
+            .find(|t| !self.is_time_held_new_record(*t))
+            .unwrap_or(0);
+
+        let right_pos = (middle..=self.time)
+            .find(|t| !self.is_time_held_new_record(*t))
+            .unwrap_or(self.time);
+
+        trace!(
+            time = self.time,
+            distance = self.distance,
+            shortest = left_pos + 1,
+            longest = right_pos - 1,
+            "ways to beat the record"
+        );
+        (left_pos + 1)..right_pos
+    }
+
+    /// The race and how to beat it, such as `7ms to beat 9mm: hold 2 to 5ms`.
+    fn format_holds(&self) -> String {
+        let holds = self.record_holds();
+        if holds.is_empty() {
+            format!("{}ms to beat {}mm: no way", self.time, self.distance)
+        } else {
+            format!(
+                "{}ms to beat {}mm: hold {} to {}ms",
+                self.time,
+                self.distance,
+                holds.start,
+                holds.end - 1
+            )
+        }
+    }
+}
+
+/// The races of the sheet of paper, read either as several races or as a
+/// single race with bad kerning.
+pub struct RaceSheet {
+    races: Vec<Race>,
+    race: Race,
+}
+
+impl RaceSheet {
+    fn from_string(input: &str) -> Result<Self, ParseError> {
+        let mut lines = lines(input);
+        let times_line = lines.expect("the race times")?;
+        let distances_line = lines.expect("the record distances")?;
+
+        Ok(RaceSheet {
+            races: races_for_lines(times_line, distances_line)?,
+            race: race_for_lines(times_line, distances_line)?,
+        })
+    }
+}
+
+impl Puzzle for RaceSheet {
+    type Input = Self;
+    type Output = Uint;
+    // Races whose record is below their time used to have no ways to win
+    const VERSION: u32 = 2;
+    const QUERIES: &'static [Query] = &[Query {
+        usage: "race <n>",
+        help: "how long to hold the button for to beat race n",
+    }];
+
+    #[instrument(skip_all)]
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        RaceSheet::from_string(input)
+    }
+
+    /// The product of the ways to beat the races.
+    #[instrument(skip_all)]
+    fn part1(sheet: &Self) -> Result<Uint, SolveError> {
+        let ways = sheet.races.iter().map(|r| r.ways_to_beat_record() as Uint);
+        Ok(numeric::product(ways, |i| format!("race {}", i + 1))?)
+    }
+
+    /// The number of ways to beat the single, long race.
+    #[instrument(skip_all)]
+    fn part2(sheet: &Self) -> Result<Uint, SolveError> {
+        Ok(sheet.race.ways_to_beat_record() as Uint)
+    }
+
+    /// The ways to beat every race, or the single race.
+    fn explain(sheet: &Self, part: Part) -> Result<Option<Explanation>, SolveError> {
+        let races = match part {
+            Part::One => sheet.races.iter().collect(),
+            Part::Two => vec![&sheet.race],
+        };
+        let contributions = races
+            .into_iter()
+            .enumerate()
+            .map(|(i, race)| {
+                Contribution::new(
+                    format!("Race {}", i + 1),
+                    race.format_holds(),
+                    race.ways_to_beat_record(),
+                )
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Some(Explanation::new(Combine::Product, contributions)))
+    }
+
+    fn query(sheet: &Self, words: &[&str]) -> Option<Reply> {
+        let ["race", n] = words else {
+            return None;
+        };
+        Some(query::nth(&sheet.races, n, "race").map(|race| {
+            format!(
+                "{}, {} ways",
+                race.format_holds(),
+                race.ways_to_beat_record()
+            )
+        }))
+    }
+}
+
+fn races_for_lines(times_line: Span, distances_line: Span) -> Result<Vec<Race>, ParseError> {
+    let times = nums_for_line(times_line)?;
+    let distances = nums_for_line(distances_line)?;
+    if times.len() != distances.len() {
+        return Err(distances_line.error(format!("{} distances", times.len())));
+    }
+
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+fn nums_for_line(line: Span) -> Result<Vec<u64>, ParseError> {
+    line.split_once(':')?.1.numbers()
+}
+
+fn race_for_lines(times_line: Span, distances_line: Span) -> Result<Race, ParseError> {
+    Ok(Race {
+        time: num_for_line(times_line)?,
+        distance: num_for_line(distances_line)?,
+    })
+}
+
+fn num_for_line(line: Span) -> Result<u64, ParseError> {
+    let nums = line.split_once(':')?.1.trim();
+    let digits = nums.text.split_whitespace().collect::<String>();
+    Span::new(&digits, nums.line, nums.column).parse::<u64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::assert_answers, explain::assert_explained};
+
+    #[test]
+    fn test_example() {
+        assert_answers::<RaceSheet>(DATA_DIR, "example.txt");
+    }
+
+    #[test]
+    fn test_input() {
+        assert_answers::<RaceSheet>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_explained::<RaceSheet>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_query() {
+        let sheet = RaceSheet::parse_file(format!("{DATA_DIR}/example.txt")).unwrap();
+        assert_eq!(
+            RaceSheet::query(&sheet, &["race", "2"]),
+            Some(Ok("15ms to beat 40mm: hold 4 to 11ms, 8 ways".to_string()))
+        );
+        assert!(RaceSheet::query(&sheet, &["race", "4"]).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = RaceSheet::parse("Time:      7  15   30").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected the record distances, found the end of the input"
+        );
+    }
+}
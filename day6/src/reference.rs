@@ -0,0 +1,56 @@
+//! A brute-force reference for the outwards search of
+//! `Race::ways_to_beat_record`.
+
+use crate::{Race, RaceSheet};
+use aoc_common::{
+    generate::{Generate, Rng},
+    numeric::Uint,
+    Puzzle,
+};
+
+/// Try holding the button for every possible time.
+fn ways_to_beat_record(race: &Race) -> u64 {
+    (0..=race.time)
+        .filter(|held| held * (race.time - held) > race.distance)
+        .count() as u64
+}
+
+#[test]
+fn test_generated_sheets() {
+    for seed in 0..20 {
+        let input = RaceSheet::generate(100_000, seed);
+        let sheet = RaceSheet::parse(&input).unwrap();
+        let expected: u64 = sheet.races.iter().map(ways_to_beat_record).product();
+        assert_eq!(
+            RaceSheet::part1(&sheet).unwrap(),
+            Uint::from(expected),
+            "{input}"
+        );
+        let expected = ways_to_beat_record(&sheet.race);
+        assert_eq!(
+            RaceSheet::part2(&sheet).unwrap(),
+            Uint::from(expected),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_random_races() {
+    let mut rng = Rng::new(6);
+    for _ in 0..10_000 {
+        let time = rng.below(60);
+        // Any record, including ones too long to beat or too short to lose
+        let race = Race {
+            time,
+            distance: rng.below(time * time / 4 + 5),
+        };
+        assert_eq!(
+            race.ways_to_beat_record(),
+            ways_to_beat_record(&race),
+            "time {}, distance {}",
+            race.time,
+            race.distance
+        );
+    }
+}
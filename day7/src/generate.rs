@@ -0,0 +1,41 @@
+use crate::CamelCards;
+use aoc_common::generate::{Generate, Rng};
+
+const LABELS: &[u8] = b"23456789TJQKA";
+
+impl Generate for CamelCards {
+    /// A list of `size` bids on random hands, some of them with repeated
+    /// cards so that every hand type shows up.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::new();
+        for _ in 0..size {
+            input.push_str(&format!("{} {}\n", hand(&mut rng), rng.range(1..1000)));
+        }
+        input
+    }
+}
+
+/// A random hand, with repeated cards often enough that every hand type shows
+/// up.
+pub(crate) fn hand(rng: &mut Rng) -> String {
+    // Drawing from fewer labels makes stronger hands more likely
+    let labels: Vec<u8> = (0..rng.range(1..6)).map(|_| *rng.choose(LABELS)).collect();
+    (0..5).map(|_| char::from(*rng.choose(&labels))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        let input = CamelCards::generate(200, 7);
+        assert_eq!(input, CamelCards::generate(200, 7));
+        let cards = CamelCards::parse(&input).unwrap();
+        assert_eq!(cards.bids.len(), 200);
+        assert!(CamelCards::part1(&cards).unwrap() > 0);
+        assert!(CamelCards::part2(&cards).unwrap() > 0);
+    }
+}
@@ -0,0 +1,123 @@
+//! Both parts rank the hands differently, so each one gets its own module.
+
+pub mod part1;
+pub mod part2;
+
+mod generate;
+
+use aoc_common::{
+    explain::{Combine, Explanation},
+    input::{lines, Span},
+    numeric::Uint,
+    query::{Query, Reply},
+    ParseError, Part, Puzzle, SolveError,
+};
+use tracing::instrument;
+
+/// Directory holding this day's example and puzzle inputs.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+/// The list of bids, with their hands read by the rules of each part.
+pub struct CamelCards {
+    bids: Vec<part1::Bid>,
+    joker_bids: Vec<part2::Bid>,
+}
+
+impl Puzzle for CamelCards {
+    type Input = Self;
+    type Output = Uint;
+    const QUERIES: &'static [Query] = &[Query {
+        usage: "hand <cards>",
+        help: "the type of any hand under both rules, and how many hands it beats",
+    }];
+
+    #[instrument(skip_all)]
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(CamelCards {
+            bids: lines(input)
+                .map(part1::Bid::from_string)
+                .collect::<Result<_, _>>()?,
+            joker_bids: lines(input)
+                .map(part2::Bid::from_string)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// The total winnings of all the hands.
+    #[instrument(skip_all)]
+    fn part1(cards: &Self) -> Result<Uint, SolveError> {
+        Ok(part1::total_winnings(&cards.bids)?)
+    }
+
+    /// The total winnings of all the hands, with `J` cards being jokers.
+    #[instrument(skip_all)]
+    fn part2(cards: &Self) -> Result<Uint, SolveError> {
+        Ok(part2::total_winnings(&cards.joker_bids)?)
+    }
+
+    /// The winnings of every hand.
+    fn explain(cards: &Self, part: Part) -> Result<Option<Explanation>, SolveError> {
+        let contributions = match part {
+            Part::One => part1::explain_winnings(&cards.bids)?,
+            Part::Two => part2::explain_winnings(&cards.joker_bids)?,
+        };
+        Ok(Some(Explanation::new(Combine::Sum, contributions)))
+    }
+
+    fn query(cards: &Self, words: &[&str]) -> Option<Reply> {
+        let ["hand", hand] = words else {
+            return None;
+        };
+        if hand.chars().count() != 5 {
+            return Some(Err(format!("a hand has 5 cards, found `{hand}`")));
+        }
+        let hand = Span::new(hand, 1, 1);
+        let reply = part1::describe_hand(&cards.bids, hand).and_then(|plain| {
+            let jokers = part2::describe_hand(&cards.joker_bids, hand)?;
+            Ok(format!("{plain}; with jokers {jokers}"))
+        });
+        Some(reply.map_err(|error| error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::assert_answers, explain::assert_explained};
+
+    #[test]
+    fn test_example() {
+        assert_answers::<CamelCards>(DATA_DIR, "example.txt");
+    }
+
+    #[test]
+    fn test_input() {
+        assert_answers::<CamelCards>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_explained::<CamelCards>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_query() {
+        let cards = CamelCards::parse_file(format!("{DATA_DIR}/example.txt")).unwrap();
+        assert_eq!(
+            CamelCards::query(&cards, &["hand", "KTJJT"]),
+            Some(Ok(
+                "TwoPair, beats 1 of the 5 hands; with jokers FourOfAKind, beats 4 of the 5 hands"
+                    .to_string()
+            ))
+        );
+        assert_eq!(
+            CamelCards::query(&cards, &["hand", "KTJJX"]),
+            Some(Err(
+                "line 1, column 5: expected a card label, found `X`".to_string()
+            ))
+        );
+        assert!(CamelCards::query(&cards, &["hand", "KTJ"])
+            .unwrap()
+            .is_err());
+    }
+}
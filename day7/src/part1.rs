@@ -0,0 +1,308 @@
+use aoc_common::{
+    explain::Contribution,
+    input::Span,
+    numeric::{self, Overflow, Uint},
+    ParseError,
+};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use tracing::trace;
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+enum CardLabel {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Cards {
+    cards: Vec<CardLabel>,
+}
+
+impl Cards {
+    fn from_string(input: Span) -> Result<Cards, ParseError> {
+        let cards = input
+            .text
+            .char_indices()
+            .map(|(i, c)| match c {
+                '2' => Ok(CardLabel::Two),
+                '3' => Ok(CardLabel::Three),
+                '4' => Ok(CardLabel::Four),
+                '5' => Ok(CardLabel::Five),
+                '6' => Ok(CardLabel::Six),
+                '7' => Ok(CardLabel::Seven),
+                '8' => Ok(CardLabel::Eight),
+                '9' => Ok(CardLabel::Nine),
+                'T' => Ok(CardLabel::Ten),
+                'J' => Ok(CardLabel::Jack),
+                'Q' => Ok(CardLabel::Queen),
+                'K' => Ok(CardLabel::King),
+                'A' => Ok(CardLabel::Ace),
+                _ => Err(input.slice(i..(i + c.len_utf8())).error("a card label")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Cards { cards })
+    }
+
+    fn hand_type(&self) -> HandType {
+        let map = self.cards.iter().fold(HashMap::new(), |mut map, card| {
+            let count = map.entry(card).or_insert(0);
+            *count += 1;
+            map
+        });
+
+        match map.len() {
+            1 => HandType::FiveOfAKind,
+            2 => {
+                if map.values().any(|count| *count == 4) {
+                    HandType::FourOfAKind
+                } else {
+                    HandType::FullHouse
+                }
+            }
+            3 => {
+                if map.values().any(|count| *count == 3) {
+                    HandType::ThreeOfAKind
+                } else {
+                    HandType::TwoPair
+                }
+            }
+            4 => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+impl Ord for Cards {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for (this_card, other_card) in self.cards.iter().zip(other.cards.iter()) {
+            if this_card == other_card {
+                continue;
+            }
+            return this_card.cmp(other_card);
+        }
+        Ordering::Equal
+    }
+}
+
+impl fmt::Display for Cards {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The labels in the order of `CardLabel`
+        const LABELS: &[u8] = b"23456789TJQKA";
+        for card in &self.cards {
+            write!(f, "{}", char::from(LABELS[*card as usize]))?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialOrd for Cards {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Hand {
+    cards: Cards,
+    r#type: HandType,
+}
+
+impl Hand {
+    fn from_string(input: Span) -> Result<Hand, ParseError> {
+        let cards = Cards::from_string(input)?;
+        Ok(Hand {
+            r#type: cards.hand_type(),
+            cards,
+        })
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.r#type == other.r#type {
+            self.cards.cmp(&other.cards)
+        } else {
+            self.r#type.cmp(&other.r#type)
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Bid {
+    hand: Hand,
+    bid: u32,
+}
+
+impl Bid {
+    pub fn from_string(input: Span) -> Result<Bid, ParseError> {
+        let (cards_input, bid_input) = input.split_once(' ')?;
+        let hand = Hand::from_string(cards_input)?;
+        let bid = bid_input.parse::<u32>()?;
+        Ok(Bid { hand, bid })
+    }
+}
+
+impl Ord for Bid {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hand.cmp(&other.hand)
+    }
+}
+
+impl PartialOrd for Bid {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The bids from the weakest hand to the strongest one.
+fn ranked(bids: &[Bid]) -> Vec<&Bid> {
+    let mut bids = bids.iter().collect::<Vec<&Bid>>();
+
+    bids.sort();
+    bids
+}
+
+/// What the bid of the hand of `rank`, counting from 0, wins.
+fn winnings(rank: usize, bid: &Bid) -> Result<Uint, Overflow> {
+    numeric::mul((rank + 1) as Uint, bid.bid.into(), || {
+        format!("the winnings of rank {}", rank + 1)
+    })
+}
+
+pub fn total_winnings(bids: &[Bid]) -> Result<Uint, Overflow> {
+    let ranked = ranked(bids);
+    let winnings = ranked
+        .iter()
+        .enumerate()
+        .map(|(rank, bid)| {
+            trace!(rank = rank + 1, hand = ?bid.hand, bid = bid.bid);
+            winnings(rank, bid)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    numeric::sum(winnings, |rank| format!("rank {}", rank + 1))
+}
+
+/// The type of the hand `cards`, which needn't be one of `bids`, and how many
+/// of the hands of `bids` it beats.
+pub fn describe_hand(bids: &[Bid], cards: Span) -> Result<String, ParseError> {
+    let hand = Hand::from_string(cards)?;
+    let beaten = bids.iter().filter(|bid| bid.hand < hand).count();
+    Ok(format!(
+        "{:?}, beats {beaten} of the {} hands",
+        hand.r#type,
+        bids.len()
+    ))
+}
+
+/// The winnings of every hand.
+pub fn explain_winnings(bids: &[Bid]) -> Result<Vec<Contribution>, Overflow> {
+    ranked(bids)
+        .iter()
+        .enumerate()
+        .map(|(rank, bid)| {
+            Contribution::new(
+                format!("Rank {}", rank + 1),
+                format!(
+                    "{} ({:?}), bid {}",
+                    bid.hand.cards, bid.hand.r#type, bid.bid
+                ),
+                winnings(rank, bid)?,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate::hand, CamelCards};
+    use aoc_common::{generate::Rng, Puzzle};
+
+    #[test]
+    fn test_parse_error() {
+        let error = CamelCards::parse("32T3K 765\nT55X5 684").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected a card label, found `X`"
+        );
+    }
+
+    /// The type of `cards` from how often each label shows up.
+    fn expected_type(cards: &str) -> HandType {
+        let mut counts: Vec<usize> = "23456789TJQKA"
+            .chars()
+            .map(|label| cards.matches(label).count())
+            .collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        match counts[..2] {
+            [5, _] => HandType::FiveOfAKind,
+            [4, _] => HandType::FourOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [3, _] => HandType::ThreeOfAKind,
+            [2, 2] => HandType::TwoPair,
+            [2, _] => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    #[test]
+    fn test_hand_order() {
+        let mut rng = Rng::new(71);
+        let mut hands: Vec<(String, Hand)> = (0..300)
+            .map(|_| {
+                let text = hand(&mut rng);
+                let hand = Hand::from_string(Span::new(&text, 1, 1)).unwrap();
+                assert_eq!(hand.r#type, expected_type(&text), "{text}");
+                (text, hand)
+            })
+            .collect();
+        for (a_text, a) in &hands {
+            for (b_text, b) in &hands {
+                let order = a.cmp(b);
+                assert_eq!(order, b.cmp(a).reverse(), "{a_text} against {b_text}");
+                assert_eq!(order == Ordering::Equal, a_text == b_text);
+                if a.r#type != b.r#type {
+                    assert_eq!(order, a.r#type.cmp(&b.r#type), "{a_text} against {b_text}");
+                }
+            }
+        }
+        // Sorting puts every hand below all the hands after it
+        hands.sort_by(|(_, a), (_, b)| a.cmp(b));
+        for (i, (a_text, a)) in hands.iter().enumerate() {
+            for (b_text, b) in &hands[i..] {
+                assert!(a <= b, "{a_text} against {b_text}");
+            }
+        }
+    }
+}
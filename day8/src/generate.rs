@@ -0,0 +1,179 @@
+use crate::Network;
+use aoc_common::generate::{Generate, Rng};
+
+/// How long the cycles of the ghosts are, in rounds of instructions. They're
+/// primes so that the ghosts only meet at the end of all their cycles.
+const ROUNDS: &[usize] = &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+/// At most as many ghosts as in the real inputs, which keeps part 2's answer
+/// within a `u64`.
+const MAX_GHOSTS: usize = 6;
+
+impl Generate for Network {
+    /// A network of about `size` nodes, shaped like the real inputs: every
+    /// ghost, the one starting at `AAA` included, walks a cycle that goes
+    /// through a single node ending with `Z` after a whole number of rounds of
+    /// instructions. Wrong turns lead anywhere else in the network.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let (rounds, instructions) = shape(&mut rng, size);
+        let mut walks = vec![];
+        let mut count = 0;
+        for (ghost, &rounds) in rounds.iter().enumerate() {
+            let steps = rounds * instructions.len();
+            let mut names = vec![start_name(ghost)];
+            names.extend((1..steps).map(|i| node_name(count + i, size)));
+            names.push(end_name(ghost));
+            count += steps;
+            // The end goes back to the first node after the start
+            walks.push((names, 1));
+        }
+        write_network(&mut rng, &instructions, &walks)
+    }
+}
+
+/// A network of about `size` nodes unlike the real inputs: every ghost first
+/// walks a few steps that aren't a whole number of rounds of instructions,
+/// then loops through several nodes ending with `Z`. So the steps a ghost is
+/// on these nodes aren't multiples of the length of its loop, and its first
+/// one doesn't tell when the ghosts meet. They all are on one at once before
+/// the longest loop starts over.
+#[cfg(test)]
+pub(crate) fn generate_unaligned(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let (rounds, instructions) = shape(&mut rng, size);
+    let length = instructions.len();
+    let lead_ins: Vec<usize> = rounds
+        .iter()
+        .map(|_| rng.range(1..2 * length as u64 + 1) as usize)
+        .collect();
+    let longest = rounds.iter().max().unwrap() * length;
+    let meeting = lead_ins.iter().max().unwrap() + rng.below(longest as u64) as usize;
+    let mut walks = vec![];
+    let mut count = 0;
+    for (ghost, (&rounds, &lead_in)) in rounds.iter().zip(&lead_ins).enumerate() {
+        let steps = lead_in + rounds * length;
+        let mut names = vec![start_name(ghost)];
+        names.extend((1..steps).map(|i| node_name(count + i, size)));
+        count += steps;
+        // The ghost is on its end at the meeting step, and a few more nodes of
+        // its loop end with `Z` too
+        let end = lead_in + (meeting - lead_in) % (rounds * length);
+        for other in 0..rng.range(1..4) {
+            let step = lead_in + rng.below((rounds * length) as u64) as usize;
+            names[step] = format!("{}{}Z", letters(ghost, 2), letters(other as usize, 2));
+        }
+        names[end] = end_name(ghost);
+        // The last node goes back to the first node after the lead-in
+        walks.push((names, lead_in));
+    }
+    write_network(&mut rng, &instructions, &walks)
+}
+
+/// How many rounds of random instructions the loop of each ghost lasts, for a
+/// network of about `size` nodes.
+fn shape(rng: &mut Rng, size: usize) -> (Vec<usize>, Vec<char>) {
+    let ghosts = (size / 100).clamp(1, MAX_GHOSTS);
+    let mut rounds = ROUNDS.to_vec();
+    rng.shuffle(&mut rounds);
+    rounds.truncate(ghosts);
+    let length = (size / rounds.iter().sum::<usize>()).max(1);
+    let instructions = (0..length)
+        .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+        .collect();
+    (rounds, instructions)
+}
+
+/// The network where every ghost walks through the names of its walk, then
+/// from its last node back to the node at the given step of the walk.
+fn write_network(rng: &mut Rng, instructions: &[char], walks: &[(Vec<String>, usize)]) -> String {
+    // The nodes are all named first, so that wrong turns can go to any of them
+    let all: Vec<&String> = walks.iter().flat_map(|(names, _)| names).collect();
+    let mut lines = vec![];
+    for (names, back) in walks {
+        for (i, name) in names.iter().enumerate() {
+            let next = if i + 1 < names.len() {
+                &names[i + 1]
+            } else {
+                &names[*back]
+            };
+            let wrong = rng.choose(&all);
+            let (left, right) = match instructions[i % instructions.len()] {
+                'L' => (next, *wrong),
+                _ => (*wrong, next),
+            };
+            lines.push(format!("{name} = ({left}, {right})"));
+        }
+    }
+    rng.shuffle(&mut lines);
+    format!(
+        "{}\n\n{}\n",
+        instructions.iter().collect::<String>(),
+        lines.join("\n")
+    )
+}
+
+fn start_name(ghost: usize) -> String {
+    match ghost {
+        0 => "AAA".to_string(),
+        _ => format!("{}A", letters(ghost, 2)),
+    }
+}
+
+fn end_name(ghost: usize) -> String {
+    match ghost {
+        0 => "ZZZ".to_string(),
+        _ => format!("{}Z", letters(ghost, 2)),
+    }
+}
+
+/// The name of the `index`th node in a network of `size` nodes, which never
+/// ends with `A` or `Z`.
+fn node_name(index: usize, size: usize) -> String {
+    let mut width = 3;
+    while 24usize.pow(width as u32) < size.max(1) + 1 {
+        width += 1;
+    }
+    letters(index, width)
+}
+
+/// `n` written with `width` letters from `B` to `Y`.
+fn letters(mut n: usize, width: usize) -> String {
+    let mut letters = vec![b'B'; width];
+    for letter in letters.iter_mut().rev() {
+        *letter = b'B' + (n % 24) as u8;
+        n /= 24;
+    }
+    String::from_utf8(letters).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{numeric::Uint, Puzzle};
+
+    #[test]
+    fn test_generate() {
+        let input = Network::generate(600, 8);
+        assert_eq!(input, Network::generate(600, 8));
+        let network = Network::parse(&input).unwrap();
+        let ghosts = network.map.keys().filter(|name| name.ends_with('A'));
+        assert_eq!(ghosts.count(), 6);
+        let length = network.instructions.len() as Uint;
+        let steps = Network::part1(&network).unwrap();
+        assert_eq!(steps % length, 0);
+        assert_eq!(Network::part2(&network).unwrap() % steps, 0);
+    }
+
+    #[test]
+    fn test_generate_unaligned() {
+        let input = generate_unaligned(600, 8);
+        assert_eq!(input, generate_unaligned(600, 8));
+        let network = Network::parse(&input).unwrap();
+        let starts = network.map.keys().filter(|name| name.ends_with('A'));
+        assert_eq!(starts.count(), 6);
+        let ends = network.map.keys().filter(|name| name.ends_with('Z'));
+        assert!(ends.count() > 6);
+        assert!(Network::part1(&network).is_ok());
+    }
+}
@@ -0,0 +1,509 @@
+mod generate;
+#[cfg(test)]
+mod reference;
+
+use aoc_common::{
+    explain::{Combine, Contribution, Explanation},
+    input::{lines, Span},
+    math::{self, CrtError},
+    numeric::{self, Int, Integer, Overflow, Uint},
+    query::{Query, Reply},
+    ParseError, Part, Puzzle, SolveError,
+};
+use std::collections::HashMap;
+use tracing::{debug, instrument, trace, trace_span};
+
+/// Directory holding this day's example and puzzle inputs.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+#[derive(Debug)]
+enum Instruction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+struct Node {
+    position: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+    /// The node of `input`, and the spans of its left and right nodes, which
+    /// only the whole network can tell exist.
+    fn from_string(input: Span) -> Result<(Node, [Span; 2]), ParseError> {
+        // Input looks like: "position = (left, right)"
+        let (mut position, options) = input.split_once('=')?;
+        position = position.trim();
+        let (mut left, mut right) = options
+            .trim()
+            .strip_prefix("(")?
+            .strip_suffix(")")?
+            .split_once(',')?;
+        left = left.trim();
+        right = right.trim();
+        let node = Node {
+            position: position.text.to_string(),
+            left: left.text.to_string(),
+            right: right.text.to_string(),
+        };
+        Ok((node, [left, right]))
+    }
+
+    fn next_position(&self, instruction: &Instruction) -> String {
+        match instruction {
+            Instruction::Left => self.left.clone(),
+            Instruction::Right => self.right.clone(),
+        }
+    }
+}
+
+/// The walk of a ghost up to the step where it's back on a node at the same
+/// instruction as before, from where it loops forever.
+struct GhostLoop {
+    start: String,
+    /// The node after every step of the walk, from the start until it loops.
+    path: Vec<String>,
+    /// The step the walk gets back to after the last step of `path`.
+    loop_start: usize,
+}
+
+impl GhostLoop {
+    fn loop_length(&self) -> usize {
+        self.path.len() - self.loop_start
+    }
+
+    /// The node the ghost is on after `steps` steps.
+    fn position(&self, steps: Uint) -> &str {
+        let step = match usize::try_from(steps) {
+            Ok(step) if step < self.path.len() => step,
+            _ => {
+                let into_loop = (steps - self.loop_start as Uint) % self.loop_length() as Uint;
+                self.loop_start + into_loop as usize
+            }
+        };
+        &self.path[step]
+    }
+
+    /// The steps of the loop that end on a node ending with `Z`.
+    fn loop_ends(&self) -> impl Iterator<Item = usize> + '_ {
+        (self.loop_start..self.path.len()).filter(|&step| self.path[step].ends_with('Z'))
+    }
+}
+
+pub struct Network {
+    instructions: Vec<Instruction>,
+    map: HashMap<String, Node>,
+}
+
+impl Network {
+    fn from_string(input: &str) -> Result<Self, ParseError> {
+        let mut lines_iter = lines(input);
+        let instructions = Self::_parse_instructions(lines_iter.expect("the instructions")?)?;
+        // Skip blank line
+        let blank_line = lines_iter.expect("a blank line")?;
+        if !blank_line.text.trim().is_empty() {
+            return Err(blank_line.error("a blank line"));
+        }
+        let mut map = HashMap::new();
+        let mut targets = vec![];
+        for line in lines_iter {
+            let (node, [left, right]) = Node::from_string(line)?;
+            map.insert(node.position.clone(), node);
+            targets.extend([left, right]);
+        }
+        if let Some(target) = targets.iter().find(|t| !map.contains_key(t.text)) {
+            return Err(target.error("a node of the network"));
+        }
+        debug!(
+            instructions = instructions.len(),
+            nodes = map.len(),
+            "parsed the network"
+        );
+        Ok(Network { instructions, map })
+    }
+
+    fn _parse_instructions(input: Span) -> Result<Vec<Instruction>, ParseError> {
+        if input.text.is_empty() {
+            return Err(input.end_error("`L` or `R`"));
+        }
+        input
+            .text
+            .char_indices()
+            .map(|(i, c)| match c {
+                'L' => Ok(Instruction::Left),
+                'R' => Ok(Instruction::Right),
+                _ => Err(input.slice(i..(i + c.len_utf8())).error("`L` or `R`")),
+            })
+            .collect::<Result<Vec<Instruction>, _>>()
+    }
+
+    fn next_node(&self, current_node: &Node, instruction: &Instruction) -> &Node {
+        // Parsing checked that every node leads to nodes of the network
+        &self.map[&current_node.next_position(instruction)]
+    }
+
+    /// The steps from `start` to the first node for which `is_end` holds, and
+    /// that node, where `goal` describes the nodes it holds for. Past
+    /// `instructions * nodes` steps a node is reached at the same instruction
+    /// twice, so the walk gives up there.
+    fn walk<F>(&self, start: &str, goal: &str, is_end: F) -> Result<(Uint, &Node), SolveError>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let mut current_node = self
+            .map
+            .get(start)
+            .ok_or_else(|| SolveError::Unsolvable(format!("there's no node {start}")))?;
+        let max_steps = self.instructions.len() * self.map.len();
+        let mut steps = 0;
+        for instruction in self.instructions.iter().cycle().take(max_steps) {
+            steps += 1;
+            current_node = self.next_node(current_node, instruction);
+            trace!(steps, ?instruction, node = current_node.position, "step");
+            if is_end(&current_node.position) {
+                return Ok((steps, current_node));
+            }
+        }
+        Err(SolveError::Unsolvable(format!(
+            "{start} never reaches {goal}"
+        )))
+    }
+
+    fn run_instructions(&self) -> Result<Uint, SolveError> {
+        let (steps, _) = self.walk("AAA", "ZZZ", |position| position == "ZZZ")?;
+        Ok(steps)
+    }
+
+    fn run_ghost(&self) -> Result<Uint, SolveError> {
+        let ghosts = self.ghost_loops()?;
+        all_on_ends(&ghosts)?.ok_or_else(|| {
+            SolveError::Unsolvable(
+                "the ghosts are never all on nodes ending with Z at once".to_string(),
+            )
+        })
+    }
+
+    fn ghost_loops(&self) -> Result<Vec<GhostLoop>, SolveError> {
+        let mut starting_positions: Vec<&String> =
+            self.map.keys().filter(|k| k.ends_with('A')).collect();
+        if starting_positions.is_empty() {
+            return Err(SolveError::Unsolvable(
+                "there's no node ending with A".to_string(),
+            ));
+        }
+        starting_positions.sort();
+        Ok(starting_positions
+            .into_iter()
+            .map(|start| self.ghost_loop(start))
+            .collect())
+    }
+
+    /// Walk the ghost starting at `start` until it's on a node at the same
+    /// instruction as on an earlier step.
+    fn ghost_loop(&self, start: &str) -> GhostLoop {
+        let _ghost = trace_span!("ghost", start).entered();
+        let mut current_node = &self.map[start];
+        let mut seen = HashMap::new();
+        let mut path = vec![];
+        for (i, instruction) in self.instructions.iter().enumerate().cycle() {
+            let state = (current_node.position.as_str(), i);
+            if let Some(&loop_start) = seen.get(&state) {
+                let ghost = GhostLoop {
+                    start: start.to_string(),
+                    path,
+                    loop_start,
+                };
+                let ends: Vec<usize> = ghost.loop_ends().collect();
+                debug!(
+                    loop_start,
+                    length = ghost.loop_length(),
+                    ?ends,
+                    "ghost loops"
+                );
+                return ghost;
+            }
+            seen.insert(state, path.len());
+            path.push(current_node.position.clone());
+            current_node = self.next_node(current_node, instruction);
+        }
+        unreachable!("parsing checked that there are instructions")
+    }
+
+    /// The nodes from `start` to the first node ending with `Z`, without
+    /// following the instructions around forever if there's none.
+    fn find_path(&self, start: &str) -> Result<Vec<&str>, String> {
+        let mut current_node = self
+            .map
+            .get(start)
+            .ok_or_else(|| format!("there's no node {start}"))?;
+        let mut path = vec![current_node.position.as_str()];
+        // Past this many steps a node is reached at the same instruction twice
+        let max_steps = self.instructions.len() * self.map.len();
+        for instruction in self.instructions.iter().cycle().take(max_steps) {
+            let next_position = current_node.next_position(instruction);
+            current_node = self
+                .map
+                .get(&next_position)
+                .ok_or_else(|| format!("there's no node {next_position}"))?;
+            path.push(&current_node.position);
+            if current_node.position.ends_with('Z') {
+                return Ok(path);
+            }
+        }
+        Err(format!("{start} never reaches a node ending with Z"))
+    }
+}
+
+/// The first step at which all `ghosts` are on nodes ending with `Z` at
+/// once, if there's any.
+fn all_on_ends(ghosts: &[GhostLoop]) -> Result<Option<Uint>, SolveError> {
+    // Until every ghost loops, the steps can only be tried one by one
+    let looping = ghosts.iter().map(|g| g.loop_start).max().unwrap_or(0);
+    let on_ends = |steps: Uint| ghosts.iter().all(|g| g.position(steps).ends_with('Z'));
+    if let Some(steps) = (1..looping as Uint).find(|&steps| on_ends(steps)) {
+        return Ok(Some(steps));
+    }
+    // From then on, a ghost is on a node ending with `Z` at the steps
+    // congruent to one of the ends of its loop modulo its length
+    let mut systems: Vec<Vec<(Int, Int)>> = vec![vec![]];
+    for ghost in ghosts {
+        let at = || format!("the loop of the ghost from {}", ghost.start);
+        let length: Int = numeric::convert(ghost.loop_length(), at)?;
+        let ends = ghost
+            .loop_ends()
+            .map(|end| Ok((numeric::convert(end, at)?, length)))
+            .collect::<Result<Vec<_>, Overflow>>()?;
+        systems = systems
+            .into_iter()
+            .flat_map(|system| {
+                ends.iter()
+                    .map(move |&end| [system.clone(), vec![end]].concat())
+            })
+            .collect();
+    }
+    let at = || "the loops of the ghosts".to_string();
+    let looping: Int = numeric::convert(looping, at)?;
+    let mut first = None;
+    for system in systems {
+        let (x, m) = match math::crt(&system) {
+            Ok(solution) => solution,
+            Err(CrtError::Incompatible(_)) => continue,
+            Err(CrtError::Overflow) => {
+                return Err(SolveError::Overflow(Overflow {
+                    at: at(),
+                    operation: "the least common multiple of their lengths".to_string(),
+                    r#type: Int::NAME,
+                }))
+            }
+        };
+        // The first solution from the step every ghost loops at
+        let steps = if x >= looping {
+            x
+        } else {
+            let loops = (looping - x - 1) / m + 1;
+            numeric::add(x, numeric::mul(loops, m, at)?, at)?
+        };
+        let steps: Uint = numeric::convert(steps, at)?;
+        trace!(?system, steps, "ghosts on ends");
+        first = Some(first.map_or(steps, |first: Uint| first.min(steps)));
+    }
+    Ok(first)
+}
+
+impl Puzzle for Network {
+    type Input = Self;
+    type Output = Uint;
+    const QUERIES: &'static [Query] = &[Query {
+        usage: "path <node>",
+        help: "the nodes from a node to the first one ending with Z",
+    }];
+
+    #[instrument(skip_all)]
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Network::from_string(input)
+    }
+
+    /// The steps it takes to go from `AAA` to `ZZZ`.
+    #[instrument(skip_all)]
+    fn part1(network: &Self) -> Result<Uint, SolveError> {
+        network.run_instructions()
+    }
+
+    /// The steps it takes for all ghosts to be on nodes ending with `Z`.
+    #[instrument(skip_all)]
+    fn part2(network: &Self) -> Result<Uint, SolveError> {
+        network.run_ghost()
+    }
+
+    /// The steps from `AAA` to `ZZZ`, or the step at which every ghost is on a
+    /// node ending with `Z`, and where it loops.
+    fn explain(network: &Self, part: Part) -> Result<Option<Explanation>, SolveError> {
+        let explanation = match part {
+            Part::One => Explanation::new(
+                Combine::Sum,
+                vec![Contribution::new(
+                    "AAA",
+                    "steps to ZZZ",
+                    network.run_instructions()?,
+                )?],
+            ),
+            Part::Two => {
+                let steps = network.run_ghost()?;
+                Explanation::new(
+                    Combine::Minimum,
+                    network
+                        .ghost_loops()?
+                        .iter()
+                        .map(|g| {
+                            let detail = format!(
+                                "on {}, looping every {} steps from step {}",
+                                g.position(steps),
+                                g.loop_length(),
+                                g.loop_start
+                            );
+                            Contribution::new(g.start.clone(), detail, steps)
+                        })
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+        };
+        Ok(Some(explanation))
+    }
+
+    fn query(network: &Self, words: &[&str]) -> Option<Reply> {
+        // The walks of the input are thousands of steps long
+        const SHOWN: usize = 10;
+        let ["path", start] = words else {
+            return None;
+        };
+        Some(network.find_path(start).map(|path| {
+            let steps = path.len() - 1;
+            let nodes = if path.len() > SHOWN {
+                format!("{} -> ... -> {}", path[..SHOWN].join(" -> "), path[steps])
+            } else {
+                path.join(" -> ")
+            };
+            format!("{nodes} ({steps} steps)")
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::assert_answers, explain::assert_explained};
+
+    #[test]
+    fn test_example_part1() {
+        assert_answers::<Network>(DATA_DIR, "example1.txt");
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_answers::<Network>(DATA_DIR, "example2.txt");
+    }
+
+    #[test]
+    fn test_input() {
+        assert_answers::<Network>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_explained::<Network>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_query() {
+        let network = Network::parse_file(format!("{DATA_DIR}/example1.txt")).unwrap();
+        assert_eq!(
+            Network::query(&network, &["path", "AAA"]),
+            Some(Ok(
+                "AAA -> BBB -> AAA -> BBB -> AAA -> BBB -> ZZZ (6 steps)".to_string()
+            ))
+        );
+        let network = Network::parse_file(format!("{DATA_DIR}/input.txt")).unwrap();
+        let path = Network::query(&network, &["path", "AAA"]).unwrap().unwrap();
+        assert!(path.ends_with(" -> ... -> ZZZ (16531 steps)"), "{path}");
+        assert_eq!(
+            Network::query(&network, &["path", "ABC"]),
+            Some(Err("there's no node ABC".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Network::parse("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA ZZZ)")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 8: expected `,`, found `AAA ZZZ`"
+        );
+        let error = Network::parse("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, QQQ)")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 13: expected a node of the network, found `QQQ`"
+        );
+    }
+
+    #[test]
+    fn test_unsolvable() {
+        let network =
+            Network::parse("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(
+            Network::part1(&network),
+            Err(SolveError::Unsolvable("AAA never reaches ZZZ".to_string()))
+        );
+        assert_eq!(
+            Network::part2(&network),
+            Err(SolveError::Unsolvable(
+                "the ghosts are never all on nodes ending with Z at once".to_string()
+            ))
+        );
+        let network = Network::parse_file(format!("{DATA_DIR}/example2.txt")).unwrap();
+        assert_eq!(
+            Network::part1(&network),
+            Err(SolveError::Unsolvable("there's no node AAA".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unaligned_loops() {
+        // The first ghost is on a Z node at every odd step, the second at every
+        // even step from step 2, so their first Z nodes don't tell when they meet
+        let network = Network::parse(
+            "L\n\n\
+             11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11Z, 11Z)\n\
+             22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)",
+        )
+        .unwrap();
+        assert_eq!(
+            Network::part2(&network),
+            Err(SolveError::Unsolvable(
+                "the ghosts are never all on nodes ending with Z at once".to_string()
+            ))
+        );
+        // The first ghost reaches a Z node after 2 steps, then every 3 steps;
+        // the second after 1 step, then every 2 steps
+        let network = Network::parse(
+            "L\n\n\
+             11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11C, 11C)\n11C = (11D, 11D)\n\
+             11D = (11Z, 11Z)\n\
+             22A = (22Z, 22Z)\n22Z = (22B, 22B)\n22B = (22Z, 22Z)",
+        )
+        .unwrap();
+        assert_eq!(Network::part2(&network), Ok(5));
+        assert_eq!(
+            Network::explain(&network, Part::Two)
+                .unwrap()
+                .unwrap()
+                .total(),
+            Ok(Some(5))
+        );
+    }
+}
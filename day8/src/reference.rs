@@ -0,0 +1,64 @@
+//! A brute-force reference for the ghosts, which the solver follows one by
+//! one until they loop to combine the steps of their loops on nodes ending
+//! with `Z`.
+
+use crate::{generate::generate_unaligned, Network};
+use aoc_common::{
+    generate::{Generate, Rng},
+    numeric::Uint,
+    Puzzle,
+};
+
+/// Walk all ghosts at once until they're all on nodes ending with `Z`.
+fn ghost_steps(network: &Network) -> Uint {
+    let mut positions: Vec<&str> = network
+        .map
+        .keys()
+        .filter(|position| position.ends_with('A'))
+        .map(String::as_str)
+        .collect();
+    let mut steps = 0;
+    for instruction in network.instructions.iter().cycle() {
+        if positions.iter().all(|position| position.ends_with('Z')) {
+            break;
+        }
+        for position in &mut positions {
+            *position = network
+                .next_node(&network.map[*position], instruction)
+                .position
+                .as_str();
+        }
+        steps += 1;
+    }
+    steps
+}
+
+#[test]
+fn test_generated_networks() {
+    let mut rng = Rng::new(8);
+    for seed in 0..10 {
+        // Small enough networks have few ghosts, whose cycles only line up
+        // after a few million steps
+        let input = Network::generate(rng.range(100..500) as usize, seed);
+        let network = Network::parse(&input).unwrap();
+        assert_eq!(
+            Network::part2(&network).unwrap(),
+            ghost_steps(&network),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_unaligned_networks() {
+    let mut rng = Rng::new(15);
+    for seed in 0..10 {
+        let input = generate_unaligned(rng.range(100..700) as usize, seed);
+        let network = Network::parse(&input).unwrap();
+        assert_eq!(
+            Network::part2(&network).unwrap(),
+            ghost_steps(&network),
+            "{input}"
+        );
+    }
+}
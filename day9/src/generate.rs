@@ -0,0 +1,46 @@
+use crate::OasisReport;
+use aoc_common::generate::{Generate, Rng};
+
+/// How many values every history has, like in the real inputs.
+const VALUES: i64 = 21;
+
+impl Generate for OasisReport {
+    /// A report of `size` histories, each one the values of a polynomial of
+    /// up to the fourth degree.
+    fn generate(size: usize, seed: u64) -> String {
+        let mut rng = Rng::new(seed);
+        let mut input = String::new();
+        for _ in 0..size {
+            let coefficients: Vec<i64> =
+                (0..rng.range(1..6)).map(|_| rng.range_i64(-5..6)).collect();
+            let values: Vec<String> = (0..VALUES)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |value, coefficient| value * x + coefficient)
+                        .to_string()
+                })
+                .collect();
+            input.push_str(&values.join(" "));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        let input = OasisReport::generate(200, 9);
+        assert_eq!(input, OasisReport::generate(200, 9));
+        let report = OasisReport::parse(&input).unwrap();
+        assert_eq!(report.entries.len(), 200);
+        OasisReport::part1(&report).unwrap();
+        OasisReport::part2(&report).unwrap();
+    }
+}
@@ -0,0 +1,221 @@
+mod generate;
+
+use aoc_common::{
+    explain::{Combine, Contribution, Explanation},
+    input::{lines, Span},
+    numeric::{self, Int, Overflow},
+    query::{self, Query, Reply},
+    ParseError, Part, Puzzle, SolveError,
+};
+use tracing::{debug, instrument, trace};
+
+/// Directory holding this day's example and puzzle inputs.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data");
+
+struct OasisHistory {
+    values: Vec<Int>,
+}
+
+impl OasisHistory {
+    fn from_string(input: Span) -> Result<Self, ParseError> {
+        let values = input.numbers()?;
+        if values.is_empty() {
+            return Err(input.end_error("a value"));
+        }
+        Ok(OasisHistory { values })
+    }
+
+    fn predict_next_value(&self, backwards: bool) -> Result<Int, Overflow> {
+        let value = Self::get_next_difference_value(self.values.clone(), 0, backwards)?;
+        debug!(values = ?self.values, value, "predicted");
+        Ok(value)
+    }
+
+    fn get_next_difference_value(
+        values: Vec<Int>,
+        level: usize,
+        backwards: bool,
+    ) -> Result<Int, Overflow> {
+        let at = || format!("the differences of {values:?}");
+        let mut differences = vec![];
+        for index in 1..values.len() {
+            let this = values[index];
+            let prev = values[index - 1];
+            differences.push(numeric::sub(this, prev, at)?);
+        }
+
+        let next_diff_value = if differences.iter().all(|x| *x == 0) {
+            0
+        } else {
+            Self::get_next_difference_value(differences, level + 1, backwards)?
+        };
+
+        let value = if backwards {
+            numeric::sub(values[0], next_diff_value, at)?
+        } else {
+            numeric::add(values[values.len() - 1], next_diff_value, at)?
+        };
+        trace!(level, ?values, value, "difference level");
+        Ok(value)
+    }
+}
+
+pub struct OasisReport {
+    entries: Vec<OasisHistory>,
+}
+
+impl OasisReport {
+    fn from_string(input: &str) -> Result<Self, ParseError> {
+        let entries: Vec<OasisHistory> = lines(input)
+            .map(OasisHistory::from_string)
+            .collect::<Result<_, _>>()?;
+        Ok(OasisReport { entries })
+    }
+
+    fn predict_next_values(&self, backwards: bool) -> Result<Vec<Int>, Overflow> {
+        self.entries
+            .iter()
+            .map(|entry| entry.predict_next_value(backwards))
+            .collect()
+    }
+}
+
+impl Puzzle for OasisReport {
+    type Input = Self;
+    type Output = Int;
+    const QUERIES: &'static [Query] = &[Query {
+        usage: "predict <n>",
+        help: "the next and previous values of history n",
+    }];
+
+    #[instrument(skip_all)]
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        OasisReport::from_string(input)
+    }
+
+    /// The sum of the predicted next values.
+    #[instrument(skip_all)]
+    fn part1(report: &Self) -> Result<Int, SolveError> {
+        let values = report.predict_next_values(false)?;
+        Ok(numeric::sum(values, |i| format!("history {}", i + 1))?)
+    }
+
+    /// The sum of the predicted previous values.
+    #[instrument(skip_all)]
+    fn part2(report: &Self) -> Result<Int, SolveError> {
+        let values = report.predict_next_values(true)?;
+        Ok(numeric::sum(values, |i| format!("history {}", i + 1))?)
+    }
+
+    /// The prediction of every history.
+    fn explain(report: &Self, part: Part) -> Result<Option<Explanation>, SolveError> {
+        let backwards = part == Part::Two;
+        let contributions = report
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let values: Vec<String> = entry.values.iter().map(Int::to_string).collect();
+                Contribution::new(
+                    format!("History {}", i + 1),
+                    values.join(" "),
+                    entry.predict_next_value(backwards)?,
+                )
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Some(Explanation::new(Combine::Sum, contributions)))
+    }
+
+    fn query(report: &Self, words: &[&str]) -> Option<Reply> {
+        let ["predict", n] = words else {
+            return None;
+        };
+        let overflowed = |overflow| SolveError::from(overflow).to_string();
+        Some(query::nth(&report.entries, n, "history").and_then(|entry| {
+            let values: Vec<String> = entry.values.iter().map(Int::to_string).collect();
+            Ok(format!(
+                "{}: next {}, previous {}",
+                values.join(" "),
+                entry.predict_next_value(false).map_err(overflowed)?,
+                entry.predict_next_value(true).map_err(overflowed)?
+            ))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{answers::assert_answers, explain::assert_explained, generate::Rng};
+
+    #[test]
+    fn test_example() {
+        assert_answers::<OasisReport>(DATA_DIR, "example.txt");
+    }
+
+    #[test]
+    fn test_input() {
+        assert_answers::<OasisReport>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_explained::<OasisReport>(DATA_DIR, "input.txt");
+    }
+
+    #[test]
+    fn test_query() {
+        let report = OasisReport::parse_file(format!("{DATA_DIR}/example.txt")).unwrap();
+        assert_eq!(
+            OasisReport::query(&report, &["predict", "3"]),
+            Some(Ok("10 13 16 21 30 45: next 68, previous 5".to_string()))
+        );
+        assert!(OasisReport::query(&report, &["predict", "4"])
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn test_reader() {
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+        let report = OasisReport::parse_reader(input.as_bytes()).unwrap();
+        assert_eq!(OasisReport::part1(&report).unwrap(), 114);
+        assert_eq!(OasisReport::part2(&report).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = OasisReport::parse("0 3 6 9 12 15\n1 3 6 10 15 2l")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 13: invalid number `2l`: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_predict_polynomial() {
+        let mut rng = Rng::new(9);
+        for _ in 0..1000 {
+            let degree = rng.below(6) as usize;
+            let coefficients: Vec<i64> = (0..=degree).map(|_| rng.range_i64(-9..10)).collect();
+            let value = |x: i64| coefficients.iter().rev().fold(0, |sum, c| sum * x + c);
+            // Enough values for the differences to reach zero
+            let length = degree as i64 + 2 + rng.below(10) as i64;
+            let history = OasisHistory {
+                values: (0..length).map(|x| value(x) as Int).collect(),
+            };
+            assert_eq!(
+                history.predict_next_value(false),
+                Ok(value(length) as Int),
+                "{coefficients:?} over {length} values"
+            );
+            assert_eq!(
+                history.predict_next_value(true),
+                Ok(value(-1) as Int),
+                "{coefficients:?} over {length} values"
+            );
+        }
+    }
+}